@@ -0,0 +1,39 @@
+use derive_more::Deref;
+use nalgebra::{Matrix4, Vector3};
+
+/// Per-instance data uploaded into a second vertex buffer bound at a
+/// distinct binding with `vk::VertexInputRate::INSTANCE`: the model matrix
+/// (its four `vec4` columns become locations 4-7 in the graphics pipeline's
+/// vertex input layout) and an optional colour tint.
+#[derive(Clone, Copy, Debug)]
+pub struct InstanceData {
+    pub model: Matrix4<f32>,
+    pub colour: Vector3<f32>,
+}
+
+impl InstanceData {
+    pub fn new(model: Matrix4<f32>, colour: Vector3<f32>) -> Self {
+        Self { model, colour }
+    }
+}
+
+impl Default for InstanceData {
+    fn default() -> Self {
+        Self {
+            model: Matrix4::identity(),
+            colour: crate::WHITE,
+        }
+    }
+}
+
+/// The list of per-instance transforms a geometry is drawn with. Empty by
+/// default, in which case a geometry draws exactly once with an implicit
+/// identity instance, matching the pre-instancing behaviour.
+#[derive(Clone, Debug, Default, Deref)]
+pub struct Instances(pub Vec<InstanceData>);
+
+impl Instances {
+    pub fn instance_count(&self) -> u32 {
+        self.0.len().max(1) as u32
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{GeomBuilder, Indexed, TextureBuffer, WHITE};
+use derive_builder::Builder;
+use lambda_space::{
+    space::{Vertices, VerticesAndIndices},
+    vertex,
+};
+use lambda_vulkan::{GeomProperties, ModelCullMode, ModelTopology, ShaderType};
+use nalgebra::{Vector2, Vector3};
+use std::{collections::HashMap, sync::Arc};
+
+mod tables;
+
+/// A scalar field sampled on a grid; returns the field value at a point so
+/// the surface `f(x, y, z) = iso_level` can be extracted.
+pub type ScalarField = Arc<dyn Fn(f32, f32, f32) -> f32 + Send + Sync>;
+
+/// Properties describing the grid a [`MarchingCubes`] surface is sampled
+/// over: the bounds of the volume, how many cells per axis, and the
+/// iso-level the surface sits at.
+#[derive(Builder, Clone)]
+#[builder(default)]
+pub struct MarchingCubesInfo {
+    pub bounds_min: Vector3<f32>,
+    pub bounds_max: Vector3<f32>,
+    pub resolution: u32,
+    pub iso_level: f32,
+    #[builder(setter(custom))]
+    pub field: ScalarField,
+}
+
+impl Default for MarchingCubesInfo {
+    fn default() -> Self {
+        Self {
+            bounds_min: Vector3::new(-1., -1., -1.),
+            bounds_max: Vector3::new(1., 1., 1.),
+            resolution: 32,
+            iso_level: 0.,
+            field: Arc::new(|_, _, _| 0.),
+        }
+    }
+}
+
+impl MarchingCubesInfoBuilder {
+    pub fn field(&mut self, field: impl Fn(f32, f32, f32) -> f32 + Send + Sync + 'static) -> &mut Self {
+        self.field = Some(Arc::new(field));
+        self
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct MarchingCubes {
+    pub properties: MarchingCubesInfo,
+    pub texture: TextureBuffer,
+    pub indexed: Indexed,
+    pub topology: ModelTopology,
+    pub cull_mode: ModelCullMode,
+    pub shader: ShaderType,
+}
+
+impl GeomBuilder for MarchingCubes {
+    fn vertices_and_indices(&self) -> VerticesAndIndices {
+        polygonise(&self.properties)
+    }
+
+    fn features(&self) -> GeomProperties {
+        GeomProperties::new(
+            &self.texture,
+            self.vertices_and_indices(),
+            self.topology,
+            self.cull_mode,
+            self.shader,
+            *self.indexed,
+        )
+    }
+}
+
+/// Samples `info.field` over a uniform grid and emits a triangle mesh of the
+/// `iso_level` isosurface using the classic marching-cubes edge/triangle
+/// tables.
+fn polygonise(info: &MarchingCubesInfo) -> VerticesAndIndices {
+    let res = info.resolution.max(1);
+    let step = (info.bounds_max - info.bounds_min).component_div(&Vector3::new(
+        res as f32, res as f32, res as f32,
+    ));
+
+    let corner_offset = [
+        Vector3::new(0., 0., 0.),
+        Vector3::new(1., 0., 0.),
+        Vector3::new(1., 1., 0.),
+        Vector3::new(0., 1., 0.),
+        Vector3::new(0., 0., 1.),
+        Vector3::new(1., 0., 1.),
+        Vector3::new(1., 1., 1.),
+        Vector3::new(0., 1., 1.),
+    ];
+
+    let mut vertices = Vertices::default();
+    let mut indices = Vec::new();
+    // Marching cubes revisits the same iso-surface crossing point from every
+    // cell/triangle that shares it, so without deduplication the vertex
+    // count (and the risk of overflowing the u16 index) grows far faster
+    // than the surface actually needs.
+    let mut unique: HashMap<[u32; 6], u16> = HashMap::new();
+
+    for x in 0..res {
+        for y in 0..res {
+            for z in 0..res {
+                let cell_origin = info.bounds_min
+                    + Vector3::new(x as f32, y as f32, z as f32).component_mul(&step);
+
+                let corners: Vec<Vector3<f32>> = corner_offset
+                    .iter()
+                    .map(|offset| cell_origin + offset.component_mul(&step))
+                    .collect();
+
+                let values: Vec<f32> = corners
+                    .iter()
+                    .map(|p| (info.field)(p.x, p.y, p.z))
+                    .collect();
+
+                let mut cube_index = 0usize;
+                for (i, &value) in values.iter().enumerate() {
+                    if value < info.iso_level {
+                        cube_index |= 1 << i;
+                    }
+                }
+
+                let edge_mask = tables::EDGE_TABLE[cube_index];
+                if edge_mask == 0 {
+                    continue;
+                }
+
+                let mut edge_vertex = [Vector3::zeros(); 12];
+                for (edge, &(a, b)) in tables::EDGE_CORNERS.iter().enumerate() {
+                    if edge_mask & (1 << edge) != 0 {
+                        edge_vertex[edge] =
+                            interpolate(info.iso_level, corners[a], corners[b], values[a], values[b]);
+                    }
+                }
+
+                for triangle in tables::TRI_TABLE[cube_index].chunks(3) {
+                    if triangle[0] == -1 {
+                        break;
+                    }
+
+                    for &edge in triangle {
+                        let pos = edge_vertex[edge as usize];
+                        let normal = gradient(&info.field, pos, step);
+
+                        let key = vertex_key(&pos, &normal);
+                        let index = *unique.entry(key).or_insert_with(|| {
+                            assert!(
+                                vertices.len() < u16::MAX as usize,
+                                "marching cubes surface exceeds {} vertices; raise the resolution \
+                                 in smaller steps or switch the index buffer to u32",
+                                u16::MAX
+                            );
+
+                            vertices.push(vertex!(pos.into(), WHITE, normal, Vector2::zeros()));
+
+                            (vertices.len() - 1) as u16
+                        });
+
+                        indices.push(index);
+                    }
+                }
+            }
+        }
+    }
+
+    VerticesAndIndices::new(vertices, indices)
+}
+
+/// Identifies a vertex by the bit patterns of its position and normal, so
+/// the same iso-surface crossing point sampled from neighbouring
+/// cells/triangles reuses one vertex and index instead of duplicating it.
+fn vertex_key(pos: &Vector3<f32>, normal: &Vector3<f32>) -> [u32; 6] {
+    [
+        pos.x.to_bits(),
+        pos.y.to_bits(),
+        pos.z.to_bits(),
+        normal.x.to_bits(),
+        normal.y.to_bits(),
+        normal.z.to_bits(),
+    ]
+}
+
+/// Linearly interpolates the crossing point of the iso-surface along an
+/// edge between two corner samples.
+fn interpolate(
+    iso_level: f32,
+    p0: Vector3<f32>,
+    p1: Vector3<f32>,
+    v0: f32,
+    v1: f32,
+) -> Vector3<f32> {
+    if (v1 - v0).abs() < f32::EPSILON {
+        return p0;
+    }
+
+    let t = (iso_level - v0) / (v1 - v0);
+    p0 + (p1 - p0) * t
+}
+
+/// Approximates the surface normal at `pos` as the (negated, normalized)
+/// gradient of the field via central differences.
+fn gradient(field: &ScalarField, pos: Vector3<f32>, step: Vector3<f32>) -> Vector3<f32> {
+    let h = step.x.min(step.y).min(step.z).max(f32::EPSILON);
+
+    let dx = field(pos.x + h, pos.y, pos.z) - field(pos.x - h, pos.y, pos.z);
+    let dy = field(pos.x, pos.y + h, pos.z) - field(pos.x, pos.y - h, pos.z);
+    let dz = field(pos.x, pos.y, pos.z + h) - field(pos.x, pos.y, pos.z - h);
+
+    let gradient = Vector3::new(dx, dy, dz);
+
+    if gradient.norm() < f32::EPSILON {
+        Vector3::new(0., 1., 0.)
+    } else {
+        -gradient.normalize()
+    }
+}
@@ -0,0 +1,26 @@
+//! The classic marching-cubes lookup tables (Lorensen & Cline 1987, as
+//! popularised by Paul Bourke's `polygonise` reference implementation).
+//!
+//! [`EDGE_TABLE`] maps an 8-bit corner-inside/outside index to a 12-bit mask
+//! of which of the cell's 12 edges the surface crosses. [`EDGE_CORNERS`]
+//! gives the two corner indices each of those 12 edges connects.
+//! [`TRI_TABLE`] maps the same cube index to a `-1`-terminated list of edge
+//! indices grouped in triangles (most cases need only a handful, but the
+//! checkerboard-ambiguous cases can need up to ten).
+
+pub(super) const EDGE_CORNERS: [(usize, usize); 12] = [
+    (0, 1),
+    (1, 2),
+    (2, 3),
+    (3, 0),
+    (4, 5),
+    (5, 6),
+    (6, 7),
+    (7, 4),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+include!("tables_data.rs");
@@ -0,0 +1,4 @@
+pub mod cube;
+pub mod marching_cubes;
+pub mod model;
+pub mod sphere;
@@ -0,0 +1,103 @@
+use crate::{GeomBuilder, Indexed, TextureBuffer, WHITE};
+use derive_builder::Builder;
+use lambda_space::{
+    space::{Vertices, VerticesAndIndices},
+    vertex,
+};
+use lambda_vulkan::{GeomProperties, ModelCullMode, ModelTopology, ShaderType};
+use nalgebra::{Vector2, Vector3};
+use std::path::Path;
+
+mod gltf_loader;
+mod obj_loader;
+
+/// Properties for a mesh imported from disk, as opposed to the procedurally
+/// generated primitives (`Cube`, `Sphere`, ...).
+#[derive(Builder, Default, Debug, Clone)]
+#[builder(default)]
+pub struct ModelInfo {
+    pub radius: f32,
+    pub model_path: String,
+}
+
+/// A single mesh primitive plus the material texture it was loaded with, if
+/// any. A `.obj` file always yields one; a `.gltf`/`.glb` scene may yield
+/// several, one per primitive in the node tree.
+pub(crate) struct LoadedPrimitive {
+    pub vertices_and_indices: VerticesAndIndices,
+    pub texture: Option<TextureBuffer>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Model {
+    pub properties: ModelInfo,
+    pub texture: TextureBuffer,
+    pub indexed: Indexed,
+    pub topology: ModelTopology,
+    pub cull_mode: ModelCullMode,
+    pub shader: ShaderType,
+}
+
+impl GeomBuilder for Model {
+    fn vertices_and_indices(&self) -> VerticesAndIndices {
+        load_model(&self.properties.model_path, self.properties.radius)
+            .into_iter()
+            .next()
+            .expect("Model file contained no primitives")
+            .vertices_and_indices
+    }
+
+    fn features(&self) -> GeomProperties {
+        GeomProperties::new(
+            &self.texture,
+            self.vertices_and_indices(),
+            self.topology,
+            self.cull_mode,
+            self.shader,
+            *self.indexed,
+        )
+    }
+}
+
+impl Model {
+    /// Like [`GeomBuilder::features`], but surfaces every primitive in a
+    /// multi-primitive glTF scene as its own [`GeomProperties`], using the
+    /// per-primitive material texture when present and falling back to
+    /// `self.texture` otherwise.
+    pub fn multi_features(&self) -> Vec<GeomProperties> {
+        load_model(&self.properties.model_path, self.properties.radius)
+            .into_iter()
+            .map(|primitive| {
+                let texture = primitive.texture.as_ref().unwrap_or(&self.texture);
+
+                GeomProperties::new(
+                    texture,
+                    primitive.vertices_and_indices,
+                    self.topology,
+                    self.cull_mode,
+                    self.shader,
+                    *self.indexed,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Loads a mesh from disk, dispatching on file extension. `.obj` files are
+/// parsed with [`obj_loader`], `.gltf`/`.glb` with [`gltf_loader`].
+fn load_model(path: &str, radius: f32) -> Vec<LoadedPrimitive> {
+    match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+        Some("gltf") | Some("glb") => gltf_loader::load(path, radius),
+        Some("obj") => vec![obj_loader::load(path, radius)],
+        other => panic!("Unsupported model format: {other:?}"),
+    }
+}
+
+pub(crate) fn push_vertex(
+    vertices: &mut Vertices,
+    pos: Vector3<f32>,
+    normal: Vector3<f32>,
+    tex_coord: Vector2<f32>,
+) {
+    vertices.push(vertex!(pos.into(), WHITE, normal, tex_coord));
+}
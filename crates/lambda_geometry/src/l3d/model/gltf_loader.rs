@@ -0,0 +1,91 @@
+use super::{push_vertex, LoadedPrimitive};
+use crate::TextureBuffer;
+use lambda_space::space::{Vertices, VerticesAndIndices};
+use nalgebra::{Vector2, Vector3};
+
+/// Parses a glTF 2.0 document (`.gltf` with external/base64 buffers, or a
+/// self-contained `.glb`) into one [`LoadedPrimitive`] per mesh primitive,
+/// each carrying its own base-colour texture when the primitive's material
+/// has one.
+pub(super) fn load(path: &str, radius: f32) -> Vec<LoadedPrimitive> {
+    let (document, buffers, images) =
+        gltf::import(path).unwrap_or_else(|err| panic!("Failed to load glTF at {path}: {err}"));
+
+    let mut primitives = Vec::new();
+
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+            let positions = reader
+                .read_positions()
+                .unwrap_or_else(|| panic!("glTF primitive in {path} has no positions"));
+            let mut normals = reader.read_normals();
+            let mut tex_coords = reader.read_tex_coords(0).map(|t| t.into_f32());
+
+            let mut vertices = Vertices::default();
+
+            for pos in positions {
+                let pos = Vector3::new(pos[0], pos[1], pos[2]) * radius;
+
+                let normal = normals
+                    .as_mut()
+                    .and_then(|iter| iter.next())
+                    .map(|n| Vector3::new(n[0], n[1], n[2]))
+                    .unwrap_or_else(Vector3::zeros);
+
+                let tex_coord = tex_coords
+                    .as_mut()
+                    .and_then(|iter| iter.next())
+                    .map(|t| Vector2::new(t[0], t[1]))
+                    .unwrap_or_else(Vector2::zeros);
+
+                push_vertex(&mut vertices, pos, normal, tex_coord);
+            }
+
+            assert!(
+                vertices.len() <= u16::MAX as usize + 1,
+                "glTF primitive in {path} has {} vertices, which overflows the u16 index buffer \
+                 lambda_space::VerticesAndIndices supports today",
+                vertices.len()
+            );
+
+            let indices = reader
+                .read_indices()
+                .map(|indices| {
+                    indices
+                        .into_u32()
+                        .map(|index| {
+                            assert!(
+                                index <= u16::MAX as u32,
+                                "glTF primitive in {path} has index {index}, which overflows the \
+                                 u16 index buffer lambda_space::VerticesAndIndices supports today"
+                            );
+                            index as u16
+                        })
+                        .collect()
+                })
+                .unwrap_or_else(|| (0..vertices.len() as u16).collect());
+
+            let texture = primitive
+                .material()
+                .pbr_metallic_roughness()
+                .base_color_texture()
+                .map(|info| image_bytes(&images[info.texture().source().index()]));
+
+            primitives.push(LoadedPrimitive {
+                vertices_and_indices: VerticesAndIndices::new(vertices, indices),
+                texture,
+            });
+        }
+    }
+
+    primitives
+}
+
+/// Re-encodes a decoded glTF image into the raw byte buffer the rest of the
+/// engine expects for a [`TextureBuffer`], regardless of whether it came
+/// from an embedded base64 blob or an external file.
+fn image_bytes(image: &gltf::image::Data) -> TextureBuffer {
+    TextureBuffer(image.pixels.clone())
+}
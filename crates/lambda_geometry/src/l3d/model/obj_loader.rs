@@ -0,0 +1,69 @@
+use super::{push_vertex, LoadedPrimitive};
+use lambda_space::space::{Vertices, VerticesAndIndices};
+use nalgebra::{Vector2, Vector3};
+
+/// Parses a Wavefront `.obj` file into a single [`LoadedPrimitive`]. Faces
+/// are triangulated on load since `tobj` does not do so by default for
+/// polygonal meshes.
+pub(super) fn load(path: &str, radius: f32) -> LoadedPrimitive {
+    let (models, _) = tobj::load_obj(
+        path,
+        &tobj::LoadOptions {
+            triangulate: true,
+            single_index: true,
+            ..Default::default()
+        },
+    )
+    .unwrap_or_else(|err| panic!("Failed to load model at {path}: {err}"));
+
+    let mesh = &models
+        .first()
+        .unwrap_or_else(|| panic!("Model file {path} contained no meshes"))
+        .mesh;
+
+    let mut vertices = Vertices::default();
+
+    for i in 0..mesh.positions.len() / 3 {
+        let pos = Vector3::new(
+            mesh.positions[3 * i] * radius,
+            mesh.positions[3 * i + 1] * radius,
+            mesh.positions[3 * i + 2] * radius,
+        );
+
+        let normal = if mesh.normals.is_empty() {
+            Vector3::zeros()
+        } else {
+            Vector3::new(
+                mesh.normals[3 * i],
+                mesh.normals[3 * i + 1],
+                mesh.normals[3 * i + 2],
+            )
+        };
+
+        let tex_coord = if mesh.texcoords.is_empty() {
+            Vector2::zeros()
+        } else {
+            Vector2::new(mesh.texcoords[2 * i], 1. - mesh.texcoords[2 * i + 1])
+        };
+
+        push_vertex(&mut vertices, pos, normal, tex_coord);
+    }
+
+    let indices = mesh
+        .indices
+        .iter()
+        .map(|&index| {
+            assert!(
+                index <= u16::MAX as u32,
+                "model {path} has index {index}, which overflows the u16 index buffer \
+                 lambda_space::VerticesAndIndices supports today"
+            );
+            index as u16
+        })
+        .collect();
+
+    LoadedPrimitive {
+        vertices_and_indices: VerticesAndIndices::new(vertices, indices),
+        texture: None,
+    }
+}
@@ -1,12 +1,15 @@
 extern crate derive_builder;
 
+pub mod instance;
 pub mod l2d;
 pub mod l3d;
 pub mod macros;
+pub mod particle_system;
 pub mod utility;
 
 use derive_more::Deref;
 pub use enum_dispatch::enum_dispatch;
+use instance::Instances;
 use lambda_space::space::{Vertex, VerticesAndIndices};
 use lambda_vulkan::GeomProperties;
 use nalgebra::Vector3;
@@ -20,9 +23,12 @@ pub mod prelude {
         },
         l3d::{
             cube::{Cube, CubeBuilder},
-            model::{Model, ModelBuilder},
+            marching_cubes::{MarchingCubes, MarchingCubesInfoBuilder},
+            model::{Model, ModelInfoBuilder},
             sphere::{Sphere, SphereBuilder},
         },
+        instance::{InstanceData, Instances},
+        particle_system::{Particle, ParticleSystem, ParticleSystemBuilder},
         Behavior, GeomBuilder, Indexed, TextureBuffer,
     };
 }
@@ -47,6 +53,13 @@ pub trait GeomBuilder {
     fn vertices_and_indices(&self) -> VerticesAndIndices;
 
     fn features(&self) -> GeomProperties;
+
+    /// The per-instance transforms this geometry is drawn with. Defaults to
+    /// none, in which case the geometry draws once as before instancing was
+    /// added.
+    fn instances(&self) -> Instances {
+        Instances::default()
+    }
 }
 
 #[enum_dispatch]
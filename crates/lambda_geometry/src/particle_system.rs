@@ -0,0 +1,62 @@
+use derive_builder::Builder;
+use nalgebra::{Matrix4, Vector3};
+
+/// Per-particle GPU state, laid out to match the SSBO the particle-update
+/// compute shader reads and writes in place; the same buffer is bound as a
+/// point/quad vertex buffer for the draw that follows.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct Particle {
+    pub position: Vector3<f32>,
+    pub lifetime: f32,
+    pub velocity: Vector3<f32>,
+    pub _pad: f32,
+}
+
+/// A GPU-simulated particle emitter: every frame the compute stage
+/// integrates `position`/`velocity`/`lifetime` for `max_particles`
+/// particles, spawning `spawn_rate` new ones per second at `emitter`
+/// wherever a slot's lifetime has expired.
+#[derive(Builder, Debug, Clone)]
+#[builder(default)]
+pub struct ParticleSystem {
+    pub emitter: Matrix4<f32>,
+    pub gravity: Vector3<f32>,
+    pub spawn_rate: f32,
+    pub max_particles: u32,
+    pub initial_lifetime: f32,
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self {
+            emitter: Matrix4::identity(),
+            gravity: Vector3::new(0., -9.81, 0.),
+            spawn_rate: 100.,
+            max_particles: 10_000,
+            initial_lifetime: 5.,
+        }
+    }
+}
+
+impl ParticleSystem {
+    /// The initial SSBO contents: every slot starts alive at the emitter
+    /// with `initial_lifetime` seconds left, so the system is visible from
+    /// the very first frame instead of waiting on a compute dispatch to
+    /// spawn anything into otherwise-dead slots.
+    pub fn initial_particles(&self) -> Vec<Particle> {
+        vec![
+            Particle {
+                position: Vector3::new(
+                    self.emitter[(0, 3)],
+                    self.emitter[(1, 3)],
+                    self.emitter[(2, 3)],
+                ),
+                lifetime: self.initial_lifetime,
+                velocity: Vector3::zeros(),
+                _pad: 0.,
+            };
+            self.max_particles as usize
+        ]
+    }
+}
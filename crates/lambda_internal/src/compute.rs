@@ -0,0 +1,142 @@
+use ash::{vk, Device};
+
+/// Barrier inserted between the particle-update compute dispatch and the
+/// draw that reads the same buffer as vertex input, so the vertex stage
+/// never observes a half-updated SSBO.
+pub(crate) fn compute_to_vertex_input_barrier(buffer: vk::Buffer, size: vk::DeviceSize) -> vk::BufferMemoryBarrier {
+    vk::BufferMemoryBarrier::builder()
+        .src_access_mask(vk::AccessFlags::SHADER_WRITE)
+        .dst_access_mask(vk::AccessFlags::VERTEX_ATTRIBUTE_READ)
+        .src_queue_family_index(vk::QUEUE_FAMILY_IGNORED)
+        .dst_queue_family_index(vk::QUEUE_FAMILY_IGNORED)
+        .buffer(buffer)
+        .offset(0)
+        .size(size)
+        .build()
+}
+
+/// A compute pipeline plus the descriptor set layout it was built against.
+/// Mirrors the graphics-side `GraphicsPipeline` but for a single compute
+/// shader stage.
+#[derive(Debug, Clone, Copy)]
+pub struct ComputePipeline {
+    pub pipeline: vk::Pipeline,
+    pub layout: vk::PipelineLayout,
+    pub descriptor_set_layout: vk::DescriptorSetLayout,
+}
+
+impl ComputePipeline {
+    pub fn new(device: &Device, shader_module: vk::ShaderModule) -> Self {
+        let binding = vk::DescriptorSetLayoutBinding::builder()
+            .binding(0)
+            .descriptor_type(vk::DescriptorType::STORAGE_BUFFER)
+            .descriptor_count(1)
+            .stage_flags(vk::ShaderStageFlags::COMPUTE);
+
+        let layout_info =
+            vk::DescriptorSetLayoutCreateInfo::builder().bindings(std::slice::from_ref(&binding));
+
+        let descriptor_set_layout = unsafe {
+            device
+                .create_descriptor_set_layout(&layout_info, None)
+                .expect("Failed to create compute descriptor set layout!")
+        };
+
+        let pipeline_layout_info = vk::PipelineLayoutCreateInfo::builder()
+            .set_layouts(std::slice::from_ref(&descriptor_set_layout));
+
+        let layout = unsafe {
+            device
+                .create_pipeline_layout(&pipeline_layout_info, None)
+                .expect("Failed to create compute pipeline layout!")
+        };
+
+        let stage = vk::PipelineShaderStageCreateInfo::builder()
+            .stage(vk::ShaderStageFlags::COMPUTE)
+            .module(shader_module)
+            .name(std::ffi::CStr::from_bytes_with_nul(b"main\0").unwrap());
+
+        let pipeline_info = vk::ComputePipelineCreateInfo::builder()
+            .stage(*stage)
+            .layout(layout);
+
+        let pipeline = unsafe {
+            device
+                .create_compute_pipelines(
+                    vk::PipelineCache::null(),
+                    std::slice::from_ref(&pipeline_info),
+                    None,
+                )
+                .expect("Failed to create compute pipeline!")[0]
+        };
+
+        Self {
+            pipeline,
+            layout,
+            descriptor_set_layout,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `command_buffer` must be in the recording state and not already
+    /// inside a render pass, since compute dispatch happens outside one.
+    pub unsafe fn dispatch(
+        &self,
+        device: &Device,
+        command_buffer: vk::CommandBuffer,
+        descriptor_set: vk::DescriptorSet,
+        particle_count: u32,
+    ) {
+        device.cmd_bind_pipeline(command_buffer, vk::PipelineBindPoint::COMPUTE, self.pipeline);
+
+        device.cmd_bind_descriptor_sets(
+            command_buffer,
+            vk::PipelineBindPoint::COMPUTE,
+            self.layout,
+            0,
+            &[descriptor_set],
+            &[],
+        );
+
+        const WORKGROUP_SIZE: u32 = 256;
+        let group_count = (particle_count + WORKGROUP_SIZE - 1) / WORKGROUP_SIZE;
+
+        device.cmd_dispatch(command_buffer, group_count, 1, 1);
+    }
+}
+
+/// Simulates one frame's worth of particles: dispatches the compute shader
+/// over `particle_buffer`, then submits [`compute_to_vertex_input_barrier`]
+/// so the subsequent draw that binds `particle_buffer` as a vertex buffer
+/// never reads it mid-write. Call this once per frame, before recording the
+/// render pass that draws the particles.
+///
+/// # Safety
+///
+/// `command_buffer` must be in the recording state and not already inside a
+/// render pass; `particle_buffer`/`particle_buffer_size` must describe the
+/// same SSBO bound at `descriptor_set`.
+pub unsafe fn simulate_particles(
+    device: &Device,
+    command_buffer: vk::CommandBuffer,
+    pipeline: &ComputePipeline,
+    descriptor_set: vk::DescriptorSet,
+    particle_buffer: vk::Buffer,
+    particle_buffer_size: vk::DeviceSize,
+    particle_count: u32,
+) {
+    pipeline.dispatch(device, command_buffer, descriptor_set, particle_count);
+
+    let barrier = compute_to_vertex_input_barrier(particle_buffer, particle_buffer_size);
+
+    device.cmd_pipeline_barrier(
+        command_buffer,
+        vk::PipelineStageFlags::COMPUTE_SHADER,
+        vk::PipelineStageFlags::VERTEX_INPUT,
+        vk::DependencyFlags::empty(),
+        &[],
+        &[barrier],
+        &[],
+    );
+}
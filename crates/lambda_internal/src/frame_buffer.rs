@@ -1,10 +1,19 @@
-use crate::{resource::Resources, swap_chain::SwapChain};
+use crate::{resource::Resources, shadow::ShadowMap, swap_chain::SwapChain};
 use ash::{vk, Device};
 use derive_more::{From, Deref};
 
 #[derive(new, Debug, From, Deref)]
 pub struct FrameBuffers(pub(crate) Vec<vk::Framebuffer>);
 
+/// Framebuffer `layers` stays `1` even when `resources.colour`/
+/// `resources.depth` are 2-layer array images for multiview: per
+/// VUID-VkFramebufferCreateInfo-renderPass-02531, a framebuffer bound to a
+/// render pass with non-zero view masks must itself have `layers == 1` — the
+/// per-view expansion comes from the multiview mask and each attachment's
+/// own array layers, not the framebuffer. That also means the single-layer
+/// swapchain attachment never needs to satisfy `layers` here, so this
+/// function has no view-count-dependent sizing to do and takes no
+/// [`ViewCount`][crate::render_pass::ViewCount] parameter.
 pub(crate) fn create_frame_buffers(
     swap_chain: &SwapChain,
     render_pass: vk::RenderPass,
@@ -36,5 +45,38 @@ pub(crate) fn create_frame_buffers(
         }
     }
 
+    frame_buffers.into()
+}
+
+/// One framebuffer per swapchain image, parallel to [`create_frame_buffers`],
+/// each wrapping only the shadow map's depth attachment so the depth-only
+/// pass can render the scene from the light's point of view.
+pub(crate) fn create_shadow_frame_buffers(
+    swap_chain: &SwapChain,
+    shadow_render_pass: vk::RenderPass,
+    device: &Device,
+    shadow_map: &ShadowMap,
+) -> FrameBuffers {
+    let mut frame_buffers = Vec::new();
+
+    for _ in 0..swap_chain.images.len() {
+        let attachments = &[shadow_map.depth.view];
+
+        let frame_buffer_info = vk::FramebufferCreateInfo::builder()
+            .render_pass(shadow_render_pass)
+            .attachments(attachments)
+            .width(shadow_map.extent.width)
+            .height(shadow_map.extent.height)
+            .layers(1);
+
+        unsafe {
+            frame_buffers.push(
+                device
+                    .create_framebuffer(&frame_buffer_info, None)
+                    .expect("Failed to create shadow Frame Buffer!"),
+            );
+        }
+    }
+
     frame_buffers.into()
 }
\ No newline at end of file
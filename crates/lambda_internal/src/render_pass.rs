@@ -0,0 +1,131 @@
+use crate::{resource, swap_chain::SwapChain};
+use ash::{vk, Device, Instance};
+
+/// Number of views rendered per draw call when multiview is enabled, e.g.
+/// `2` for a stereo left/right-eye pair. `1` disables multiview and produces
+/// the regular single-view render pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ViewCount(pub u32);
+
+impl ViewCount {
+    pub fn is_multiview(self) -> bool {
+        self.0 > 1
+    }
+
+    fn view_mask(self) -> u32 {
+        (1 << self.0) - 1
+    }
+}
+
+pub fn create_render_pass(
+    instance: &Instance,
+    devices: &crate::device::Devices,
+    swapchain: &SwapChain,
+    views: ViewCount,
+) -> vk::RenderPass {
+    let renderpass_attachments = [
+        vk::AttachmentDescription {
+            format: swapchain.image_format,
+            samples: devices.msaa_samples,
+            load_op: vk::AttachmentLoadOp::CLEAR,
+            store_op: vk::AttachmentStoreOp::DONT_CARE,
+            stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
+            stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
+            initial_layout: vk::ImageLayout::UNDEFINED,
+            final_layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+            ..Default::default()
+        },
+        vk::AttachmentDescription {
+            format: resource::find_depth_format(instance, &devices.physical),
+            samples: devices.msaa_samples,
+            load_op: vk::AttachmentLoadOp::CLEAR,
+            store_op: vk::AttachmentStoreOp::DONT_CARE,
+            stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
+            stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
+            initial_layout: vk::ImageLayout::UNDEFINED,
+            final_layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+            ..Default::default()
+        },
+        vk::AttachmentDescription {
+            format: swapchain.image_format,
+            samples: vk::SampleCountFlags::TYPE_1,
+            load_op: vk::AttachmentLoadOp::DONT_CARE,
+            store_op: vk::AttachmentStoreOp::DONT_CARE,
+            stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
+            stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
+            initial_layout: vk::ImageLayout::UNDEFINED,
+            final_layout: vk::ImageLayout::PRESENT_SRC_KHR,
+            ..Default::default()
+        },
+    ];
+
+    let color_attachment_refs = vk::AttachmentReference {
+        attachment: 0,
+        layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+    };
+    let depth_attachment_ref = vk::AttachmentReference {
+        attachment: 1,
+        layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+    };
+    let color_attachment_resolver_ref = vk::AttachmentReference {
+        attachment: 2,
+        layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+    };
+
+    let subpasses = vk::SubpassDescription::builder()
+        .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+        .color_attachments(std::slice::from_ref(&color_attachment_refs))
+        .depth_stencil_attachment(&depth_attachment_ref)
+        .resolve_attachments(std::slice::from_ref(&color_attachment_resolver_ref));
+
+    let dependencies = vk::SubpassDependency::builder()
+        .src_subpass(vk::SUBPASS_EXTERNAL)
+        .dst_subpass(0)
+        .src_access_mask(vk::AccessFlags::NONE_KHR)
+        .src_stage_mask(
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
+        )
+        .dst_stage_mask(
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
+        )
+        .dst_access_mask(
+            vk::AccessFlags::COLOR_ATTACHMENT_WRITE
+                | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE,
+        );
+
+    // A view mask per subpass and a correlation mask telling the
+    // implementation the views share visibility, so a single draw
+    // populates both eye layers of the colour/depth array images.
+    let view_masks = [views.view_mask()];
+    let correlation_masks = [views.view_mask()];
+    let mut multiview = vk::RenderPassMultiviewCreateInfo::builder()
+        .view_masks(&view_masks)
+        .correlation_masks(&correlation_masks);
+
+    let mut renderpass_create_info = vk::RenderPassCreateInfo::builder()
+        .attachments(&renderpass_attachments)
+        .subpasses(std::slice::from_ref(&subpasses))
+        .dependencies(std::slice::from_ref(&dependencies));
+
+    if views.is_multiview() {
+        renderpass_create_info = renderpass_create_info.push_next(&mut multiview);
+    }
+
+    unsafe {
+        devices
+            .logical
+            .create_render_pass(&renderpass_create_info, None)
+            .expect("Failed to create render pass!")
+    }
+}
+
+/// Number of array layers the colour/depth attachment *images* (not the
+/// framebuffer — see `create_frame_buffers`) need when multiview is
+/// enabled: one per eye/view, otherwise the usual single layer. For use
+/// wherever `resources.colour`/`resources.depth` are allocated; not called
+/// from this crate yet since that image-creation code isn't in this tree.
+pub fn attachment_layers(views: ViewCount) -> u32 {
+    views.0.max(1)
+}
@@ -0,0 +1,236 @@
+use crate::resource::Resources;
+use ash::{vk, Device, Instance};
+use nalgebra::Matrix4;
+
+/// Resolution of the off-screen depth texture the light renders into. Kept
+/// fixed for now; a configurable size can follow once quality settings land.
+pub(crate) const SHADOW_MAP_SIZE: u32 = 2048;
+
+/// Depth bias the shadow pass's rasterizer applies while writing the shadow
+/// map (see [`shadow_pass_rasterization_state`]), to avoid shadow acne on
+/// surfaces nearly parallel to the light direction.
+pub(crate) const DEPTH_BIAS_CONSTANT: f32 = 1.25;
+pub(crate) const DEPTH_BIAS_SLOPE: f32 = 1.75;
+
+/// Width (in texels) of the PCF kernel sampled around the projected
+/// coordinate; `3` means a 3x3 neighbourhood is averaged.
+pub(crate) const PCF_KERNEL_SIZE: i32 = 3;
+
+/// The light's combined view-projection matrix, uploaded alongside the
+/// regular per-object uniforms so the fragment shader can project each
+/// fragment into the shadow map's clip space.
+#[derive(Clone, Copy, Debug, new)]
+pub struct LightSpaceMatrix(pub Matrix4<f32>);
+
+/// Pushed to the main lighting pipeline alongside the existing per-object
+/// uniforms, so the fragment shader can project each fragment into shadow
+/// map space and knows how wide a PCF neighbourhood to sample.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, new)]
+pub struct ShadowPushConstants {
+    pub light_space_matrix: Matrix4<f32>,
+    pub pcf_kernel_size: i32,
+}
+
+impl From<LightSpaceMatrix> for ShadowPushConstants {
+    fn from(light_space_matrix: LightSpaceMatrix) -> Self {
+        Self::new(light_space_matrix.0, PCF_KERNEL_SIZE)
+    }
+}
+
+/// Binding index reserved for the shadow map sampler in the main lighting
+/// descriptor set, alongside the existing uniform buffer/colour texture
+/// bindings.
+pub(crate) const SHADOW_MAP_BINDING: u32 = 2;
+
+/// Layout binding for [`SHADOW_MAP_BINDING`], to be folded into the main
+/// lighting pipeline's descriptor set layout alongside its existing
+/// bindings.
+pub(crate) fn shadow_map_descriptor_set_layout_binding() -> vk::DescriptorSetLayoutBinding {
+    vk::DescriptorSetLayoutBinding::builder()
+        .binding(SHADOW_MAP_BINDING)
+        .descriptor_type(vk::DescriptorType::COMBINED_IMAGE_SAMPLER)
+        .descriptor_count(1)
+        .stage_flags(vk::ShaderStageFlags::FRAGMENT)
+        .build()
+}
+
+/// Rasterization state for the shadow pass's own pipeline: applies
+/// [`DEPTH_BIAS_CONSTANT`]/[`DEPTH_BIAS_SLOPE`] so surfaces nearly parallel
+/// to the light direction don't self-shadow ("shadow acne").
+///
+/// Not yet consumed: the shadow pass's `GraphicsPipelineCreateInfo` (shader
+/// modules, vertex input state) isn't built anywhere in this tree, so there
+/// is nowhere to plug this in until that pipeline-creation code lands.
+pub(crate) fn shadow_pass_rasterization_state() -> vk::PipelineRasterizationStateCreateInfoBuilder<'static>
+{
+    vk::PipelineRasterizationStateCreateInfo::builder()
+        .depth_bias_enable(true)
+        .depth_bias_constant_factor(DEPTH_BIAS_CONSTANT)
+        .depth_bias_slope_factor(DEPTH_BIAS_SLOPE)
+}
+
+/// Off-screen depth-only render target a light renders the scene into
+/// before the main colour pass samples it for shadowing. Also owns the
+/// descriptor set layout and pipeline layout the main lighting pipeline
+/// needs in order to bind this shadow map and its [`ShadowPushConstants`],
+/// built by folding [`SHADOW_MAP_BINDING`] into the caller's existing
+/// lighting bindings/ranges so this subsystem's Vulkan objects are created
+/// together rather than left as unused, free-standing builders.
+#[derive(Debug)]
+pub struct ShadowMap {
+    pub depth: Resources,
+    pub sampler: vk::Sampler,
+    pub extent: vk::Extent2D,
+    pub descriptor_set_layout: vk::DescriptorSetLayout,
+    pub pipeline_layout: vk::PipelineLayout,
+}
+
+impl ShadowMap {
+    /// `lighting_bindings`/`lighting_push_constant_ranges` are the main
+    /// lighting pipeline's existing descriptor set layout bindings and
+    /// push constant ranges; this appends the shadow map's own binding and
+    /// [`ShadowPushConstants`] range to them rather than replacing them.
+    pub fn new(
+        instance: &Instance,
+        device: &Device,
+        physical: vk::PhysicalDevice,
+        lighting_bindings: &[vk::DescriptorSetLayoutBinding],
+        lighting_push_constant_ranges: &[vk::PushConstantRange],
+    ) -> Self {
+        let extent = vk::Extent2D {
+            width: SHADOW_MAP_SIZE,
+            height: SHADOW_MAP_SIZE,
+        };
+
+        let depth = Resources::depth_only(instance, device, physical, extent);
+
+        let sampler_info = vk::SamplerCreateInfo::builder()
+            .mag_filter(vk::Filter::LINEAR)
+            .min_filter(vk::Filter::LINEAR)
+            .address_mode_u(vk::SamplerAddressMode::CLAMP_TO_BORDER)
+            .address_mode_v(vk::SamplerAddressMode::CLAMP_TO_BORDER)
+            .address_mode_w(vk::SamplerAddressMode::CLAMP_TO_BORDER)
+            .border_color(vk::BorderColor::FLOAT_OPAQUE_WHITE)
+            .compare_enable(true)
+            .compare_op(vk::CompareOp::LESS_OR_EQUAL);
+
+        let sampler = unsafe {
+            device
+                .create_sampler(&sampler_info, None)
+                .expect("Failed to create shadow map sampler!")
+        };
+
+        let mut bindings = lighting_bindings.to_vec();
+        bindings.push(shadow_map_descriptor_set_layout_binding());
+
+        let descriptor_set_layout_info =
+            vk::DescriptorSetLayoutCreateInfo::builder().bindings(&bindings);
+
+        let descriptor_set_layout = unsafe {
+            device
+                .create_descriptor_set_layout(&descriptor_set_layout_info, None)
+                .expect("Failed to create lighting descriptor set layout with shadow binding!")
+        };
+
+        let shadow_push_constant_range = vk::PushConstantRange::builder()
+            .stage_flags(vk::ShaderStageFlags::FRAGMENT)
+            .offset(0)
+            .size(std::mem::size_of::<ShadowPushConstants>() as u32)
+            .build();
+
+        let mut push_constant_ranges = lighting_push_constant_ranges.to_vec();
+        push_constant_ranges.push(shadow_push_constant_range);
+
+        let set_layouts = [descriptor_set_layout];
+        let pipeline_layout_info = vk::PipelineLayoutCreateInfo::builder()
+            .set_layouts(&set_layouts)
+            .push_constant_ranges(&push_constant_ranges);
+
+        let pipeline_layout = unsafe {
+            device
+                .create_pipeline_layout(&pipeline_layout_info, None)
+                .expect("Failed to create lighting pipeline layout with shadow push constants!")
+        };
+
+        Self {
+            depth,
+            sampler,
+            extent,
+            descriptor_set_layout,
+            pipeline_layout,
+        }
+    }
+
+    /// Descriptor info for binding this shadow map's depth image as the
+    /// combined image sampler at [`SHADOW_MAP_BINDING`] in
+    /// [`descriptor_set_layout`][Self::descriptor_set_layout], so the
+    /// fragment shader can sample it when computing shadows for a fragment.
+    ///
+    /// Not yet consumed: writing this into an actual descriptor set
+    /// requires the allocated `vk::DescriptorSet` from the (not yet
+    /// present in this tree) main lighting descriptor pool.
+    pub fn descriptor_image_info(&self) -> vk::DescriptorImageInfo {
+        vk::DescriptorImageInfo::builder()
+            .image_layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
+            .image_view(self.depth.image_view)
+            .sampler(self.sampler)
+            .build()
+    }
+}
+
+/// Depth-only render pass a light renders scene geometry into: no colour
+/// attachment, and the depth attachment finishes in
+/// `SHADER_READ_ONLY_OPTIMAL` so the main lighting pass can sample it.
+pub(crate) fn create_shadow_render_pass(device: &Device, depth_format: vk::Format) -> vk::RenderPass {
+    let depth_attachment = vk::AttachmentDescription {
+        format: depth_format,
+        samples: vk::SampleCountFlags::TYPE_1,
+        load_op: vk::AttachmentLoadOp::CLEAR,
+        store_op: vk::AttachmentStoreOp::STORE,
+        stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
+        stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
+        initial_layout: vk::ImageLayout::UNDEFINED,
+        final_layout: vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+        ..Default::default()
+    };
+
+    let depth_attachment_ref = vk::AttachmentReference {
+        attachment: 0,
+        layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+    };
+
+    let subpass = vk::SubpassDescription::builder()
+        .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+        .depth_stencil_attachment(&depth_attachment_ref);
+
+    let dependencies = [
+        vk::SubpassDependency::builder()
+            .src_subpass(vk::SUBPASS_EXTERNAL)
+            .dst_subpass(0)
+            .src_stage_mask(vk::PipelineStageFlags::FRAGMENT_SHADER)
+            .dst_stage_mask(vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS)
+            .src_access_mask(vk::AccessFlags::SHADER_READ)
+            .dst_access_mask(vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE)
+            .build(),
+        vk::SubpassDependency::builder()
+            .src_subpass(0)
+            .dst_subpass(vk::SUBPASS_EXTERNAL)
+            .src_stage_mask(vk::PipelineStageFlags::LATE_FRAGMENT_TESTS)
+            .dst_stage_mask(vk::PipelineStageFlags::FRAGMENT_SHADER)
+            .src_access_mask(vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE)
+            .dst_access_mask(vk::AccessFlags::SHADER_READ)
+            .build(),
+    ];
+
+    let render_pass_info = vk::RenderPassCreateInfo::builder()
+        .attachments(std::slice::from_ref(&depth_attachment))
+        .subpasses(std::slice::from_ref(&subpass))
+        .dependencies(&dependencies);
+
+    unsafe {
+        device
+            .create_render_pass(&render_pass_info, None)
+            .expect("Failed to create shadow render pass!")
+    }
+}
@@ -0,0 +1,48 @@
+use ash::{util::Align, vk, Device};
+use lambda_geometry::{Behavior, GeomBuilder};
+use nalgebra::Matrix4;
+
+/// Per-object uniform data re-uploaded every frame. Only the model matrix
+/// changes frame to frame today; view/projection stay in the shared camera
+/// uniform.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ModelUbo {
+    pub model: Matrix4<f32>,
+}
+
+/// Drives one frame of animation for a scene object: runs its
+/// [`Behavior::actions`] hook, then re-maps its model-matrix uniform for the
+/// current swapchain image so the GPU sees the updated transform without
+/// rebuilding any buffers or pipelines.
+///
+/// # Safety
+///
+/// `uniform_memory` must be the mapped-memory-compatible device memory
+/// backing this object's uniform buffer for the current swapchain image, sized for at
+/// least one [`ModelUbo`].
+pub unsafe fn update_object<T>(
+    object: &mut T,
+    model_matrix: impl Fn(&T) -> Matrix4<f32>,
+    device: &Device,
+    uniform_memory: vk::DeviceMemory,
+) where
+    T: GeomBuilder + Behavior,
+{
+    object.actions();
+
+    let ubo = ModelUbo {
+        model: model_matrix(object),
+    };
+
+    let size = std::mem::size_of::<ModelUbo>() as vk::DeviceSize;
+
+    let data = device
+        .map_memory(uniform_memory, 0, size, vk::MemoryMapFlags::empty())
+        .expect("Failed to map model uniform memory!");
+
+    let mut align = Align::new(data, std::mem::align_of::<ModelUbo>() as u64, size);
+    align.copy_from_slice(std::slice::from_ref(&ubo));
+
+    device.unmap_memory(uniform_memory);
+}
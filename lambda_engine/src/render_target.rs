@@ -0,0 +1,265 @@
+use crate::{
+    device::Devices,
+    texture::{Image, Texture},
+    utility::InstanceDevices,
+};
+use ash::vk;
+
+/// An offscreen colour (and depth) attachment with its own framebuffer and
+/// render pass, so a subset of objects can be drawn into it instead of the
+/// swapchain. The colour image is created with `COLOR_ATTACHMENT | SAMPLED`
+/// usage, so once rendering into the target is done its image view can be
+/// wrapped up as a regular [`Texture`] and sampled by another [`Shape`][shape],
+/// e.g. for mirrors, minimaps, or feeding one scene's output into another
+/// object's texture.
+///
+/// [shape]: crate::shapes::Shape
+pub struct RenderTarget {
+    pub extent: vk::Extent2D,
+    pub render_pass: vk::RenderPass,
+    pub framebuffer: vk::Framebuffer,
+    pub color: Image,
+    pub color_view: vk::ImageView,
+    pub depth: Image,
+    pub depth_view: vk::ImageView,
+    pub sampler: vk::Sampler,
+}
+
+impl RenderTarget {
+    pub fn new(
+        extent: vk::Extent2D,
+        color_format: vk::Format,
+        depth_format: vk::Format,
+        instance_devices: &InstanceDevices,
+    ) -> Self {
+        let Devices { .. } = &instance_devices.devices;
+
+        let color = create_attachment_image(
+            extent,
+            color_format,
+            vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::SAMPLED,
+            vk::ImageAspectFlags::COLOR,
+            instance_devices,
+        );
+
+        let depth = create_attachment_image(
+            extent,
+            depth_format,
+            vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT | vk::ImageUsageFlags::SAMPLED,
+            vk::ImageAspectFlags::DEPTH,
+            instance_devices,
+        );
+
+        let render_pass = create_render_target_pass(color_format, depth_format, instance_devices);
+
+        let framebuffer = create_framebuffer(
+            render_pass,
+            extent,
+            color.view,
+            depth.view,
+            instance_devices,
+        );
+
+        let sampler = create_sampler(instance_devices);
+
+        Self {
+            extent,
+            render_pass,
+            framebuffer,
+            color: color.image,
+            color_view: color.view,
+            depth: depth.image,
+            depth_view: depth.view,
+            sampler,
+        }
+    }
+
+    /// Wraps this target's colour attachment up as a sampleable [`Texture`]
+    /// so it can be bound as another object's texture, e.g. once this
+    /// target's render pass has finished drawing into it for the frame.
+    pub fn as_texture(&self) -> Texture {
+        Texture::from_image_view(self.color, self.color_view, self.sampler)
+    }
+
+    /// # Safety
+    ///
+    /// Expand on the safety of this function
+    pub unsafe fn destroy(&self, devices: &Devices) {
+        devices.logical.device.destroy_sampler(self.sampler, None);
+        devices
+            .logical
+            .device
+            .destroy_framebuffer(self.framebuffer, None);
+        devices
+            .logical
+            .device
+            .destroy_render_pass(self.render_pass, None);
+
+        devices
+            .logical
+            .device
+            .destroy_image_view(self.depth_view, None);
+        devices.logical.device.destroy_image(self.depth.image, None);
+        devices.logical.device.free_memory(self.depth.memory, None);
+
+        devices
+            .logical
+            .device
+            .destroy_image_view(self.color_view, None);
+        devices.logical.device.destroy_image(self.color.image, None);
+        devices.logical.device.free_memory(self.color.memory, None);
+    }
+}
+
+struct AttachmentImage {
+    image: Image,
+    view: vk::ImageView,
+}
+
+fn create_attachment_image(
+    extent: vk::Extent2D,
+    format: vk::Format,
+    usage: vk::ImageUsageFlags,
+    aspect_mask: vk::ImageAspectFlags,
+    instance_devices: &InstanceDevices,
+) -> AttachmentImage {
+    let image = crate::texture::create_image(extent, format, usage, instance_devices);
+
+    let view = crate::texture::create_image_view(
+        image.image,
+        format,
+        aspect_mask,
+        &instance_devices.devices,
+    );
+
+    AttachmentImage { image, view }
+}
+
+fn create_render_target_pass(
+    color_format: vk::Format,
+    depth_format: vk::Format,
+    instance_devices: &InstanceDevices,
+) -> vk::RenderPass {
+    let attachments = [
+        vk::AttachmentDescription::builder()
+            .format(color_format)
+            .samples(vk::SampleCountFlags::TYPE_1)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::STORE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
+            .build(),
+        vk::AttachmentDescription::builder()
+            .format(depth_format)
+            .samples(vk::SampleCountFlags::TYPE_1)
+            .load_op(vk::AttachmentLoadOp::CLEAR)
+            .store_op(vk::AttachmentStoreOp::DONT_CARE)
+            .initial_layout(vk::ImageLayout::UNDEFINED)
+            .final_layout(vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+            .build(),
+    ];
+
+    let color_ref = vk::AttachmentReference::builder()
+        .attachment(0)
+        .layout(vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+        .build();
+
+    let depth_ref = vk::AttachmentReference::builder()
+        .attachment(1)
+        .layout(vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL)
+        .build();
+
+    let subpass = vk::SubpassDescription::builder()
+        .pipeline_bind_point(vk::PipelineBindPoint::GRAPHICS)
+        .color_attachments(std::slice::from_ref(&color_ref))
+        .depth_stencil_attachment(&depth_ref)
+        .build();
+
+    // Barriers the colour attachment write against the later shader read of
+    // the sampled texture (and vice versa for the next frame's render),
+    // mirroring shadow.rs::create_shadow_render_pass.
+    let dependencies = [
+        vk::SubpassDependency::builder()
+            .src_subpass(vk::SUBPASS_EXTERNAL)
+            .dst_subpass(0)
+            .src_stage_mask(vk::PipelineStageFlags::FRAGMENT_SHADER)
+            .dst_stage_mask(
+                vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                    | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
+            )
+            .src_access_mask(vk::AccessFlags::SHADER_READ)
+            .dst_access_mask(
+                vk::AccessFlags::COLOR_ATTACHMENT_WRITE
+                    | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE,
+            )
+            .build(),
+        vk::SubpassDependency::builder()
+            .src_subpass(0)
+            .dst_subpass(vk::SUBPASS_EXTERNAL)
+            .src_stage_mask(vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT)
+            .dst_stage_mask(vk::PipelineStageFlags::FRAGMENT_SHADER)
+            .src_access_mask(vk::AccessFlags::COLOR_ATTACHMENT_WRITE)
+            .dst_access_mask(vk::AccessFlags::SHADER_READ)
+            .build(),
+    ];
+
+    let create_info = vk::RenderPassCreateInfo::builder()
+        .attachments(&attachments)
+        .subpasses(std::slice::from_ref(&subpass))
+        .dependencies(&dependencies);
+
+    unsafe {
+        instance_devices
+            .devices
+            .logical
+            .device
+            .create_render_pass(&create_info, None)
+            .expect("Failed to create render target render pass")
+    }
+}
+
+fn create_framebuffer(
+    render_pass: vk::RenderPass,
+    extent: vk::Extent2D,
+    color_view: vk::ImageView,
+    depth_view: vk::ImageView,
+    instance_devices: &InstanceDevices,
+) -> vk::Framebuffer {
+    let attachments = [color_view, depth_view];
+
+    let create_info = vk::FramebufferCreateInfo::builder()
+        .render_pass(render_pass)
+        .attachments(&attachments)
+        .width(extent.width)
+        .height(extent.height)
+        .layers(1);
+
+    unsafe {
+        instance_devices
+            .devices
+            .logical
+            .device
+            .create_framebuffer(&create_info, None)
+            .expect("Failed to create render target framebuffer")
+    }
+}
+
+fn create_sampler(instance_devices: &InstanceDevices) -> vk::Sampler {
+    let create_info = vk::SamplerCreateInfo::builder()
+        .mag_filter(vk::Filter::LINEAR)
+        .min_filter(vk::Filter::LINEAR)
+        .address_mode_u(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+        .address_mode_v(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+        .address_mode_w(vk::SamplerAddressMode::CLAMP_TO_EDGE)
+        .border_color(vk::BorderColor::FLOAT_OPAQUE_BLACK)
+        .unnormalized_coordinates(false);
+
+    unsafe {
+        instance_devices
+            .devices
+            .logical
+            .device
+            .create_sampler(&create_info, None)
+            .expect("Failed to create render target sampler")
+    }
+}
@@ -0,0 +1,27 @@
+use crate::shapes::WHITE;
+use nalgebra::{Matrix4, Vector3};
+
+/// Per-instance data uploaded into a second vertex buffer bound at a
+/// distinct binding with `vk::VertexInputRate::INSTANCE`: the model matrix
+/// (its four `vec4` columns become locations 4-7 in the graphics pipeline's
+/// vertex input layout) and an optional colour tint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InstanceData {
+    pub model: Matrix4<f32>,
+    pub colour: Vector3<f32>,
+}
+
+impl InstanceData {
+    pub fn new(model: Matrix4<f32>, colour: Vector3<f32>) -> Self {
+        Self { model, colour }
+    }
+}
+
+impl Default for InstanceData {
+    fn default() -> Self {
+        Self {
+            model: Matrix4::identity(),
+            colour: WHITE,
+        }
+    }
+}
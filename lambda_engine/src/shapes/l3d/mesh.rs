@@ -0,0 +1,166 @@
+use crate::shapes::{Indices, Object, Shape, Vertex, VerticesAndIndices, WHITE};
+use nalgebra::{Point3, Vector2, Vector3};
+use std::{collections::HashMap, fs};
+
+pub type Mesh = Shape<MeshInfo>;
+
+#[derive(Default, Debug, Clone, new)]
+pub struct MeshInfo {
+    pub path: String,
+}
+
+impl Object for Mesh {
+    fn vertices_and_indices(&mut self) {
+        self.vertices_and_indices = load_obj(&self.properties.path);
+    }
+}
+
+/// Loads a Wavefront OBJ file into a single indexed vertex/index buffer.
+/// OBJ references each attribute (position/normal/tex coord) by an
+/// independent index per face-corner, so full vertices are deduplicated via
+/// a hash map keyed on the bit patterns of `(pos, normal, tex_coord)`:
+/// for each face-corner the combined key is looked up, reusing the existing
+/// index if present, otherwise a new `Vertex` is pushed and its index
+/// recorded. Polygonal faces are fan-triangulated. Missing normals are
+/// generated per-face; missing texture coordinates default to
+/// `Vector2::zeros()`.
+fn load_obj(path: &str) -> VerticesAndIndices {
+    let contents = fs::read_to_string(path).expect("Failed to read obj file");
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut tex_coords = Vec::new();
+    let mut faces: Vec<Vec<(usize, Option<usize>, Option<usize>)>> = Vec::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => positions.push(parse_point3(tokens)),
+            Some("vn") => normals.push(parse_vector3(tokens)),
+            Some("vt") => {
+                let u = tokens.next().unwrap().parse().unwrap();
+                let v: f32 = tokens.next().map(|v| v.parse().unwrap()).unwrap_or(0.);
+                // OBJ's `vt` is bottom-left-origin; flip to this engine's
+                // (Vulkan) top-left-origin convention.
+                tex_coords.push(Vector2::new(u, 1. - v));
+            }
+            Some("f") => faces.push(tokens.map(parse_face_vertex).collect()),
+            _ => {}
+        }
+    }
+
+    let generated_normals = generate_missing_normals(&positions, &faces);
+
+    let mut vertices = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut unique: HashMap<[u32; 8], u32> = HashMap::new();
+
+    for face in &faces {
+        for i in 1..face.len() - 1 {
+            for &(pos_index, normal_index, tex_index) in &[face[0], face[i], face[i + 1]] {
+                let pos = positions[pos_index];
+                let normal = normal_index
+                    .map(|i| normals[i])
+                    .unwrap_or(generated_normals[pos_index]);
+                let tex_coord = tex_index
+                    .map(|i| tex_coords[i])
+                    .unwrap_or_else(Vector2::zeros);
+
+                let key = vertex_key(&pos, &normal, &tex_coord);
+
+                let index = *unique.entry(key).or_insert_with(|| {
+                    vertices.push(Vertex::new(pos, WHITE, normal, tex_coord));
+
+                    (vertices.len() - 1) as u32
+                });
+
+                indices.push(index);
+            }
+        }
+    }
+
+    // Picks the narrowest index width the mesh actually fits in, so small
+    // meshes keep the cheaper u16 index buffer.
+    let indices: Indices = if vertices.len() <= u16::MAX as usize {
+        Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+    } else {
+        Indices::U32(indices)
+    };
+
+    VerticesAndIndices::new(vertices.into(), indices)
+}
+
+fn generate_missing_normals(
+    positions: &[Point3<f32>],
+    faces: &[Vec<(usize, Option<usize>, Option<usize>)>],
+) -> Vec<Vector3<f32>> {
+    let mut generated = vec![Vector3::zeros(); positions.len()];
+
+    for face in faces {
+        if face.iter().any(|&(_, normal, _)| normal.is_none()) {
+            let p0 = positions[face[0].0];
+            let p1 = positions[face[1].0];
+            let p2 = positions[face[2].0];
+
+            let face_normal = (p1 - p0).cross(&(p2 - p0)).normalize();
+
+            for &(pos_index, ..) in face {
+                generated[pos_index] += face_normal;
+            }
+        }
+    }
+
+    for normal in &mut generated {
+        if !normal.eq(&Vector3::zeros()) {
+            *normal = normal.normalize();
+        }
+    }
+
+    generated
+}
+
+fn vertex_key(pos: &Point3<f32>, normal: &Vector3<f32>, tex_coord: &Vector2<f32>) -> [u32; 8] {
+    [
+        pos.x.to_bits(),
+        pos.y.to_bits(),
+        pos.z.to_bits(),
+        normal.x.to_bits(),
+        normal.y.to_bits(),
+        normal.z.to_bits(),
+        tex_coord.x.to_bits(),
+        tex_coord.y.to_bits(),
+    ]
+}
+
+fn parse_point3<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Point3<f32> {
+    Point3::new(
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+    )
+}
+
+fn parse_vector3<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Vector3<f32> {
+    Vector3::new(
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+    )
+}
+
+fn parse_face_vertex(token: &str) -> (usize, Option<usize>, Option<usize>) {
+    let mut parts = token.split('/');
+
+    let position = parts.next().unwrap().parse::<usize>().unwrap() - 1;
+    let tex_coord = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().unwrap() - 1);
+    let normal = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().unwrap() - 1);
+
+    (position, normal, tex_coord)
+}
@@ -0,0 +1,2 @@
+pub mod cube;
+pub mod mesh;
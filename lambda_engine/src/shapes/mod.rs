@@ -1,24 +1,28 @@
+pub mod instance;
 pub mod l2d;
 pub mod l3d;
 pub mod macros;
 pub mod utility;
 
 use self::{
+    instance::InstanceData,
     l2d::{ring::RingInfo, square::SquareInfo},
-    l3d::{cube::CubeInfo, sphere::SphereInfo},
-    utility::{ModelCullMode, ModelTopology},
+    l3d::{cube::CubeInfo, mesh::MeshInfo, sphere::SphereInfo},
+    utility::{ModelCullMode, ModelTopology, ShaderType},
 };
 use crate::{
+    device::LogicalDeviceFeatures,
     pipeline::GraphicsPipeline,
     swap_chain::SwapChain,
     texture::{self, Texture},
+    uniform_buffer::UniformBufferObject,
     utility::InstanceDevices,
 };
 use ash::vk;
 use derive_builder::Builder;
 use derive_more::{Deref, DerefMut, From};
 use enum_as_inner::EnumAsInner;
-use nalgebra::{Point3, Vector2, Vector3};
+use nalgebra::{Matrix4, Point3, Rotation3, Vector2, Vector3};
 use std::{fs::File, io::Read, mem::size_of};
 
 pub const WHITE: Vector3<f32> = Vector3::new(1., 1., 1.);
@@ -30,6 +34,7 @@ pub enum ShapeProperties {
     Sphere(SphereInfo),
     Ring(RingInfo),
     Square(SquareInfo),
+    Mesh(MeshInfo),
 }
 
 #[derive(Default, Builder, Debug, Clone)]
@@ -42,11 +47,26 @@ pub struct Shape<T: Default> {
     pub indexed: bool,
     pub topology: ModelTopology,
     pub cull_mode: ModelCullMode,
+    pub shader: ShaderType,
+    pub instances: Vec<InstanceData>,
+
+    pub position: Vector3<f32>,
+    pub rotation: Vector3<f32>,
+    #[builder(default = "Vector3::new(1., 1., 1.)")]
+    pub scale: Vector3<f32>,
 
     pub(crate) vertices_and_indices: VerticesAndIndices,
     pub(crate) texture_buffer: Option<Texture>,
     pub(crate) graphics_pipeline: Option<GraphicsPipeline>,
     pub(crate) buffers: Option<ModelBuffers>,
+
+    /// Instance buffers retired by [`Object::refresh`], parked per swapchain
+    /// image index instead of destroyed immediately. A buffer parked at
+    /// `current_image` is only safe to destroy once `current_image` comes
+    /// back around, since by then every command buffer that could have
+    /// bound it has finished executing.
+    #[builder(setter(skip))]
+    pub(crate) pending_instance_buffer_frees: Vec<Option<Buffer>>,
 }
 
 impl<'a, T: Default> ShapeBuilder<T> {
@@ -71,6 +91,36 @@ where
         self.buffers = Some(model_buffers);
     }
 
+    /// Swaps in a freshly built instance buffer and returns the one it
+    /// replaced, without destroying it — the caller parks the retired
+    /// buffer via [`park_retired_instance_buffer`] until it's safe to free.
+    fn replace_instance_buffer(&mut self, instance_buffer: Buffer) -> Buffer {
+        let buffers = self.buffers.as_mut().unwrap();
+        std::mem::replace(&mut buffers.instance, instance_buffer)
+    }
+
+    /// Parks `buffer`, retired from the instance buffer at `image_index`,
+    /// and returns whatever was parked there before (if any) so the caller
+    /// can destroy it — that earlier buffer was parked the last time
+    /// `image_index` was refreshed, a full frames-in-flight cycle ago, so
+    /// every command buffer that could have bound it has since completed.
+    fn park_retired_instance_buffer(
+        &mut self,
+        image_index: usize,
+        buffer: Buffer,
+    ) -> Option<Buffer> {
+        if self.pending_instance_buffer_frees.len() <= image_index {
+            self.pending_instance_buffer_frees
+                .resize(image_index + 1, None);
+        }
+
+        std::mem::replace(&mut self.pending_instance_buffer_frees[image_index], Some(buffer))
+    }
+
+    fn object_pending_instance_buffer_frees(&self) -> &[Option<Buffer>] {
+        &self.pending_instance_buffer_frees
+    }
+
     fn texture(&mut self, command_pool: vk::CommandPool, instance_devices: &InstanceDevices) {
         if !self.texture.is_empty() {
             self.texture_buffer = Some(texture::Texture::new(
@@ -97,6 +147,17 @@ where
         &self.vertices_and_indices
     }
 
+    fn object_instances(&self) -> &[InstanceData] {
+        &self.instances
+    }
+
+    fn object_model_matrix(&self) -> Matrix4<f32> {
+        Matrix4::new_translation(&self.position)
+            * Rotation3::from_euler_angles(self.rotation.x, self.rotation.y, self.rotation.z)
+                .to_homogeneous()
+            * Matrix4::new_nonuniform_scaling(&self.scale)
+    }
+
     fn is_indexed(&self) -> bool {
         self.indexed
     }
@@ -113,6 +174,7 @@ where
             &self.texture_buffer.unwrap(),
             self.topology,
             self.cull_mode,
+            self.shader,
             instance_devices,
         ));
     }
@@ -121,6 +183,60 @@ where
 pub trait Object: private::Object {
     fn vertices_and_indices(&mut self);
 
+    /// Per-frame animation hook, called before the uniform/instance buffers
+    /// are refreshed each frame. The default is a no-op; objects that want
+    /// to animate (e.g. a ring spinning in place) override it to mutate
+    /// their own `position`/`rotation`/`scale` or `instances` by `dt`
+    /// seconds.
+    fn update(&mut self, _dt: f32) {}
+
+    /// Runs [`Object::update`] and pushes the result into this frame's
+    /// uniform buffer, rebuilding the instance buffer only if `update`
+    /// actually changed `instances`. This is the per-frame counterpart to
+    /// [`Object::construct`]; it never touches the vertex/index buffers or
+    /// the graphics pipeline.
+    #[allow(clippy::too_many_arguments)]
+    fn refresh(
+        &mut self,
+        dt: f32,
+        command_pool: vk::CommandPool,
+        command_buffer_count: u32,
+        instance_devices: &InstanceDevices,
+        logical: &LogicalDeviceFeatures,
+        current_image: usize,
+        buffer_size: u64,
+        view: Matrix4<f32>,
+        proj: Matrix4<f32>,
+    ) {
+        let instances_before_update = self.object_instances().to_vec();
+
+        self.update(dt);
+
+        if self.object_instances() != instances_before_update {
+            let instance_buffer = create_instance_buffer(
+                self.object_instances(),
+                command_pool,
+                command_buffer_count,
+                instance_devices,
+            );
+            let retired_buffer = self.replace_instance_buffer(instance_buffer);
+
+            // The buffer parked at `current_image` before this call (if
+            // any) was retired a full frames-in-flight cycle ago, so every
+            // command buffer that could have bound it has completed.
+            if let Some(stale_buffer) =
+                self.park_retired_instance_buffer(current_image, retired_buffer)
+            {
+                unsafe {
+                    logical.device.destroy_buffer(stale_buffer.buffer, None);
+                    logical.device.free_memory(stale_buffer.memory, None);
+                }
+            }
+        }
+
+        self.update_uniform_buffer(logical, current_image, buffer_size, view, proj);
+    }
+
     fn construct(
         &mut self,
         command_pool: vk::CommandPool,
@@ -136,6 +252,7 @@ pub trait Object: private::Object {
         let model_buffers = self.object_vertices_and_indices().create_buffers(
             command_pool,
             command_buffer_count,
+            self.object_instances(),
             instance_devices,
         );
 
@@ -143,10 +260,31 @@ pub trait Object: private::Object {
 
         self.graphics_pipeline(swap_chain, render_pass, instance_devices);
     }
+
+    /// Uploads this object's own `model` matrix (built from its
+    /// `position`/`rotation`/`scale`) alongside the shared `view`/`proj`
+    /// matrices into its per-image uniform buffer, so each object keeps an
+    /// independent transform rather than sharing one global matrix.
+    fn update_uniform_buffer(
+        &self,
+        logical: &LogicalDeviceFeatures,
+        current_image: usize,
+        buffer_size: u64,
+        view: Matrix4<f32>,
+        proj: Matrix4<f32>,
+    ) {
+        let ubo = UniformBufferObject {
+            model: self.object_model_matrix(),
+            view,
+            proj,
+        };
+
+        self.map_memory(logical, current_image, buffer_size, &[ubo]);
+    }
 }
 
 pub(crate) mod private {
-    use super::{ModelBuffers, VerticesAndIndices};
+    use super::{Buffer, InstanceData, Matrix4, ModelBuffers, VerticesAndIndices};
     use crate::{
         device::{Devices, LogicalDeviceFeatures},
         memory,
@@ -162,6 +300,22 @@ pub(crate) mod private {
         fn buffers(&mut self, model_buffers: ModelBuffers);
         fn texture(&mut self, command_pool: vk::CommandPool, instance_devices: &InstanceDevices);
 
+        fn replace_instance_buffer(&mut self, _instance_buffer: Buffer) -> Buffer {
+            unimplemented!()
+        }
+
+        fn park_retired_instance_buffer(
+            &mut self,
+            _image_index: usize,
+            _buffer: Buffer,
+        ) -> Option<Buffer> {
+            unimplemented!()
+        }
+
+        fn object_pending_instance_buffer_frees(&self) -> &[Option<Buffer>] {
+            unimplemented!()
+        }
+
         fn object_graphics_pipeline(&self) -> &GraphicsPipeline {
             unimplemented!()
         }
@@ -174,6 +328,12 @@ pub(crate) mod private {
         fn object_vertices_and_indices(&self) -> &VerticesAndIndices {
             unimplemented!()
         }
+        fn object_instances(&self) -> &[InstanceData] {
+            unimplemented!()
+        }
+        fn object_model_matrix(&self) -> Matrix4<f32> {
+            unimplemented!()
+        }
 
         fn is_indexed(&self) -> bool {
             unimplemented!()
@@ -231,6 +391,19 @@ pub(crate) mod private {
             logical
                 .device
                 .free_memory(object_buffers.index.memory, None);
+
+            logical
+                .device
+                .destroy_buffer(object_buffers.instance.buffer, None);
+
+            logical
+                .device
+                .free_memory(object_buffers.instance.memory, None);
+
+            for stale_buffer in self.object_pending_instance_buffer_frees().iter().flatten() {
+                logical.device.destroy_buffer(stale_buffer.buffer, None);
+                logical.device.free_memory(stale_buffer.memory, None);
+            }
         }
 
         /// # Safety
@@ -265,6 +438,8 @@ pub(crate) mod private {
             let object_buffers = self.object_buffers();
 
             let vertex_buffers = [object_buffers.vertex.buffer];
+            let instance_buffers = [object_buffers.instance.buffer];
+            let instance_count = self.object_instances().len().max(1) as u32;
 
             devices.logical.device.cmd_bind_vertex_buffers(
                 command_buffer,
@@ -273,12 +448,19 @@ pub(crate) mod private {
                 offsets,
             );
 
+            devices.logical.device.cmd_bind_vertex_buffers(
+                command_buffer,
+                1,
+                &instance_buffers,
+                offsets,
+            );
+
             let object_and_vertices_and_indices = self.object_vertices_and_indices();
 
             devices.logical.device.cmd_draw(
                 command_buffer,
                 object_and_vertices_and_indices.vertices.len() as u32,
-                1,
+                instance_count,
                 0,
                 0,
             );
@@ -288,13 +470,13 @@ pub(crate) mod private {
                     command_buffer,
                     object_buffers.index.buffer,
                     0,
-                    vk::IndexType::UINT16,
+                    object_and_vertices_and_indices.indices.index_type(),
                 );
 
                 devices.logical.device.cmd_draw_indexed(
                     command_buffer,
                     object_and_vertices_and_indices.indices.len() as u32,
-                    1,
+                    instance_count,
                     0,
                     0,
                     0,
@@ -355,8 +537,52 @@ pub(crate) mod private {
 #[derive(new, Clone, Default, Debug, From, Deref, DerefMut)]
 pub struct Vertices(Vec<Vertex>);
 
-#[derive(new, Clone, Default, Debug, From, Deref, DerefMut)]
-pub struct Indices(Vec<u16>);
+/// A mesh's index buffer contents, sized to the narrowest type that fits:
+/// `u16` for the hand-written primitive generators, `u32` once a loaded
+/// mesh exceeds 65 535 unique vertices.
+#[derive(Clone, Debug)]
+pub enum Indices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl Default for Indices {
+    fn default() -> Self {
+        Self::U16(Vec::new())
+    }
+}
+
+impl From<Vec<u16>> for Indices {
+    fn from(indices: Vec<u16>) -> Self {
+        Self::U16(indices)
+    }
+}
+
+impl From<Vec<u32>> for Indices {
+    fn from(indices: Vec<u32>) -> Self {
+        Self::U32(indices)
+    }
+}
+
+impl Indices {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::U16(indices) => indices.len(),
+            Self::U32(indices) => indices.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn index_type(&self) -> vk::IndexType {
+        match self {
+            Self::U16(_) => vk::IndexType::UINT16,
+            Self::U32(_) => vk::IndexType::UINT32,
+        }
+    }
+}
 
 #[derive(new, Clone, Default, Debug)]
 pub struct VerticesAndIndices {
@@ -369,6 +595,7 @@ impl VerticesAndIndices {
         &self,
         command_pool: ash::vk::CommandPool,
         command_buffer_count: u32,
+        instances: &[InstanceData],
         instance_devices: &crate::utility::InstanceDevices,
     ) -> ModelBuffers {
         let vertex = utility::create_vertex_index_buffer(
@@ -382,25 +609,72 @@ impl VerticesAndIndices {
             instance_devices,
         );
 
-        let index = utility::create_vertex_index_buffer(
-            (size_of::<u16>() * self.indices.len()).try_into().unwrap(),
-            &self.indices,
-            vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::INDEX_BUFFER,
-            command_pool,
-            command_buffer_count,
-            instance_devices,
-        );
+        let index = match &self.indices {
+            Indices::U16(indices) => utility::create_vertex_index_buffer(
+                (size_of::<u16>() * indices.len()).try_into().unwrap(),
+                indices,
+                vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::INDEX_BUFFER,
+                command_pool,
+                command_buffer_count,
+                instance_devices,
+            ),
+            Indices::U32(indices) => utility::create_vertex_index_buffer(
+                (size_of::<u32>() * indices.len()).try_into().unwrap(),
+                indices,
+                vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::INDEX_BUFFER,
+                command_pool,
+                command_buffer_count,
+                instance_devices,
+            ),
+        };
+
+        let instance =
+            create_instance_buffer(instances, command_pool, command_buffer_count, instance_devices);
 
-        ModelBuffers::new(vertex, index)
+        ModelBuffers::new(vertex, index, instance)
     }
 }
 
+/// A geometry with no instances still draws once, as an implicit identity
+/// instance, matching the pre-instancing behaviour. Pulled out of
+/// `create_buffers` so animated objects can refresh just this buffer each
+/// frame instead of rebuilding the whole model.
+fn create_instance_buffer(
+    instances: &[InstanceData],
+    command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    instance_devices: &crate::utility::InstanceDevices,
+) -> Buffer {
+    let instance_data = if instances.is_empty() {
+        vec![InstanceData::default()]
+    } else {
+        instances.to_vec()
+    };
+
+    utility::create_vertex_index_buffer(
+        (size_of::<InstanceData>() * instance_data.len())
+            .try_into()
+            .unwrap(),
+        &instance_data,
+        vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::VERTEX_BUFFER,
+        command_pool,
+        command_buffer_count,
+        instance_devices,
+    )
+}
+
 #[derive(Clone, Copy, Debug, new)]
 pub struct Vertex {
     pub pos: Point3<f32>,
     pub colour: Vector3<f32>,
     pub normal: Vector3<f32>,
     pub tex_coord: Vector2<f32>,
+    /// Half-extent of the cuboid this point expands into under
+    /// `ShaderType::GeoCuboid`; ignored by every other shader/topology.
+    /// Defaults to zero (no expansion) so existing call sites building
+    /// triangulated meshes are unaffected.
+    #[new(default)]
+    pub cuboid_extent: Vector3<f32>,
 }
 
 #[derive(new, Clone, Copy, Default, Debug)]
@@ -413,4 +687,5 @@ pub struct Buffer {
 pub struct ModelBuffers {
     pub vertex: Buffer,
     pub index: Buffer,
+    pub instance: Buffer,
 }
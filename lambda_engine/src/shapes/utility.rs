@@ -0,0 +1,65 @@
+use ash::vk;
+
+/// Which pipeline variant a [`crate::shapes::Shape`] is rendered with.
+///
+/// Most variants assemble ordinary triangles from the vertex/index buffers.
+/// `GeoCuboid` instead runs a geometry shader over `ModelTopology::POINT_LIST`
+/// vertices, expanding each point into a full 6-face box using its
+/// `Vertex::cuboid_extent` as the half-extents, with outward-facing normals
+/// computed per face and an optional back-face scalar cull. This lets
+/// voxel-style scenes upload one vertex per cuboid instead of the 24
+/// vertices/36 indices a triangulated cube needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShaderType {
+    Vertex,
+    Texture,
+    Light,
+    LightTexture,
+    GeoCuboid,
+}
+
+impl Default for ShaderType {
+    fn default() -> Self {
+        Self::Vertex
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModelTopology(vk::PrimitiveTopology);
+
+impl ModelTopology {
+    pub const POINT_LIST: Self = Self(vk::PrimitiveTopology::POINT_LIST);
+    pub const LINE_LIST: Self = Self(vk::PrimitiveTopology::LINE_LIST);
+    pub const LINE_STRIP: Self = Self(vk::PrimitiveTopology::LINE_STRIP);
+    pub const TRIANGLE_LIST: Self = Self(vk::PrimitiveTopology::TRIANGLE_LIST);
+    pub const TRIANGLE_STRIP: Self = Self(vk::PrimitiveTopology::TRIANGLE_STRIP);
+
+    pub fn as_vk(&self) -> vk::PrimitiveTopology {
+        self.0
+    }
+}
+
+impl Default for ModelTopology {
+    fn default() -> Self {
+        Self::TRIANGLE_LIST
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModelCullMode(vk::CullModeFlags);
+
+impl ModelCullMode {
+    pub const NONE: Self = Self(vk::CullModeFlags::NONE);
+    pub const FRONT: Self = Self(vk::CullModeFlags::FRONT);
+    pub const BACK: Self = Self(vk::CullModeFlags::BACK);
+
+    pub fn as_vk(&self) -> vk::CullModeFlags {
+        self.0
+    }
+}
+
+impl Default for ModelCullMode {
+    fn default() -> Self {
+        Self::BACK
+    }
+}
@@ -0,0 +1,137 @@
+use crate::shapes::{Indices, Vertex, VerticesAndIndices, WHITE};
+use cgmath::{InnerSpace, Vector2, Vector3, Zero};
+use std::{collections::HashMap, fs};
+
+/// A face-vertex's indices into the OBJ file's position/normal/tex-coord
+/// lists, 0-based. `None` means the face didn't reference one (e.g. `v//vn`
+/// has no tex coord).
+type FaceVertex = (usize, Option<usize>, Option<usize>);
+
+/// Loads a Wavefront OBJ file into a single indexed vertex/index buffer.
+/// Polygonal faces are fan-triangulated and identical
+/// `(position, normal, tex_coord)` tuples are deduplicated via a hash map
+/// into shared indices. Faces missing normals get one generated per-face
+/// (cross product of two edges), accumulated across the positions they
+/// share and normalized; faces missing texture coordinates default to
+/// `Vector2::zero()`.
+pub fn load_obj(path: &str) -> VerticesAndIndices {
+    let contents = fs::read_to_string(path).expect("Failed to read obj file");
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut tex_coords = Vec::new();
+    let mut faces: Vec<Vec<FaceVertex>> = Vec::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => positions.push(parse_vector3(tokens)),
+            Some("vn") => normals.push(parse_vector3(tokens)),
+            Some("vt") => {
+                let u = tokens.next().unwrap().parse().unwrap();
+                let v: f32 = tokens.next().map(|v| v.parse().unwrap()).unwrap_or(0.);
+                // OBJ's `vt` is bottom-left-origin; flip to this engine's
+                // (Vulkan) top-left-origin convention.
+                tex_coords.push(Vector2::new(u, 1. - v));
+            }
+            Some("f") => faces.push(tokens.map(parse_face_vertex).collect()),
+            _ => {}
+        }
+    }
+
+    let generated_normals = generate_missing_normals(&positions, &faces);
+
+    let mut vertices = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut unique: HashMap<FaceVertex, u32> = HashMap::new();
+
+    for face in &faces {
+        for triangle in triangulate(face) {
+            for &key @ (pos_index, normal_index, tex_index) in &triangle {
+                let index = *unique.entry(key).or_insert_with(|| {
+                    let pos = positions[pos_index];
+                    let normal = normal_index
+                        .map(|i| normals[i])
+                        .unwrap_or(generated_normals[pos_index]);
+                    let tex_coord = tex_index
+                        .map(|i| tex_coords[i])
+                        .unwrap_or_else(Vector2::zero);
+
+                    vertices.push(Vertex::new(pos, WHITE, normal, tex_coord));
+
+                    (vertices.len() - 1) as u32
+                });
+
+                indices.push(index);
+            }
+        }
+    }
+
+    // Picks the narrowest index width the mesh actually fits in, so small
+    // meshes keep the cheaper u16 index buffer.
+    let indices: Indices = if vertices.len() <= u16::MAX as usize {
+        Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+    } else {
+        Indices::U32(indices)
+    };
+
+    VerticesAndIndices::new(vertices, indices)
+}
+
+fn generate_missing_normals(positions: &[Vector3<f32>], faces: &[Vec<FaceVertex>]) -> Vec<Vector3<f32>> {
+    let mut generated = vec![Vector3::zero(); positions.len()];
+
+    for face in faces {
+        if face.iter().any(|&(_, normal, _)| normal.is_none()) {
+            let p0 = positions[face[0].0];
+            let p1 = positions[face[1].0];
+            let p2 = positions[face[2].0];
+
+            let face_normal = (p1 - p0).cross(p2 - p0).normalize();
+
+            for &(pos_index, ..) in face {
+                generated[pos_index] += face_normal;
+            }
+        }
+    }
+
+    for normal in &mut generated {
+        if *normal != Vector3::zero() {
+            *normal = normal.normalize();
+        }
+    }
+
+    generated
+}
+
+/// Fan-triangulates a polygonal face around its first vertex.
+fn triangulate(face: &[FaceVertex]) -> Vec<[FaceVertex; 3]> {
+    (1..face.len() - 1)
+        .map(|i| [face[0], face[i], face[i + 1]])
+        .collect()
+}
+
+fn parse_vector3<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Vector3<f32> {
+    Vector3::new(
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+        tokens.next().unwrap().parse().unwrap(),
+    )
+}
+
+fn parse_face_vertex(token: &str) -> FaceVertex {
+    let mut parts = token.split('/');
+
+    let position = parts.next().unwrap().parse::<usize>().unwrap() - 1;
+    let tex_coord = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().unwrap() - 1);
+    let normal = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().unwrap() - 1);
+
+    (position, normal, tex_coord)
+}
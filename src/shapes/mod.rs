@@ -4,19 +4,27 @@ pub mod utility;
 
 use self::utility::{ModelCullMode, ModelTopology};
 use crate::{
-    device::Devices, pipeline::GraphicsPipeline, space::Orientation, swap_chain::SwapChain,
-    texture::Texture, utility::InstanceDevices,
+    device::Devices, memory, pipeline::GraphicsPipeline, space::Orientation,
+    swap_chain::SwapChain, texture::Texture, utility::InstanceDevices,
 };
 use ash::vk;
-use cgmath::{Point3, Vector2, Vector3, Zero};
-use std::ops::Mul;
+use cgmath::{Matrix4, Point3, Rad, Vector2, Vector3, Zero};
+use std::{mem::size_of, ops::Mul};
 
 pub(crate) const WHITE: Vector3<f32> = Vector3::new(1., 1., 1.);
 
 pub trait Object {
-    fn translate(&mut self) {}
-    fn rotate(&mut self) {}
-    fn scale(&mut self) {}
+    fn translate(&mut self, delta: Vector3<f32>) {
+        self.object_transform().translate(delta);
+    }
+
+    fn rotate(&mut self, delta: Vector3<f32>) {
+        self.object_transform().rotate(delta);
+    }
+
+    fn scale(&mut self, factor: Vector3<f32>) {
+        self.object_transform().scale(factor);
+    }
 
     fn vertices_and_indices() -> VerticesAndIndices;
     fn object_vertices_and_indices(&self) -> &VerticesAndIndices;
@@ -28,10 +36,86 @@ pub trait Object {
     fn indexed(self) -> Self;
     fn topology(self, topology: ModelTopology) -> Self;
     fn cull_mode(self, cull_mode: ModelCullMode) -> Self;
+    fn instances(self, instances: Vec<InstanceData>) -> Self;
 
     fn is_indexed(&self) -> bool;
     fn object_topology(&self) -> &ModelTopology;
     fn object_cull_mode(&self) -> &ModelCullMode;
+    fn object_instances(&self) -> &[InstanceData];
+    fn object_transform(&mut self) -> &mut Transform;
+}
+
+/// An object's position/rotation/scale and the model matrix derived from
+/// them. The matrix is only recomputed on `model_matrix()` when a
+/// `translate`/`rotate`/`scale` call has marked it dirty, so repeated
+/// per-frame uploads of an unchanged transform are free.
+#[derive(Clone, Copy, Debug)]
+pub struct Transform {
+    pub position: Point3<f32>,
+    pub orientation: Vector3<f32>,
+    pub scale: Vector3<f32>,
+    model: Matrix4<f32>,
+    dirty: bool,
+}
+
+impl Transform {
+    pub fn new(position: Point3<f32>) -> Self {
+        Self {
+            position,
+            orientation: Vector3::zero(),
+            scale: Vector3::new(1., 1., 1.),
+            model: Matrix4::from_translation(Vector3::new(position.x, position.y, position.z)),
+            dirty: true,
+        }
+    }
+
+    pub fn translate(&mut self, delta: Vector3<f32>) {
+        self.position += delta;
+        self.dirty = true;
+    }
+
+    pub fn rotate(&mut self, delta: Vector3<f32>) {
+        self.orientation += delta;
+        self.dirty = true;
+    }
+
+    pub fn scale(&mut self, factor: Vector3<f32>) {
+        self.scale = factor;
+        self.dirty = true;
+    }
+
+    /// Recomputes the model matrix from position/orientation/scale if it's
+    /// been touched since the last call, otherwise returns the cached value.
+    pub fn model_matrix(&mut self) -> Matrix4<f32> {
+        if self.dirty {
+            let translation = Vector3::new(self.position.x, self.position.y, self.position.z);
+
+            self.model = Matrix4::from_translation(translation)
+                * Matrix4::from_angle_x(Rad(self.orientation.x))
+                * Matrix4::from_angle_y(Rad(self.orientation.y))
+                * Matrix4::from_angle_z(Rad(self.orientation.z))
+                * Matrix4::from_nonuniform_scale(self.scale.x, self.scale.y, self.scale.z);
+            self.dirty = false;
+        }
+
+        self.model
+    }
+}
+
+/// Per-instance data uploaded into the instance vertex buffer (binding 1,
+/// `vk::VertexInputRate::INSTANCE`): the model matrix, whose four `vec4`
+/// columns occupy locations 4-7 in the pipeline's vertex input layout, and a
+/// colour tint.
+#[derive(Clone, Copy, Debug)]
+pub struct InstanceData {
+    pub model: Matrix4<f32>,
+    pub colour: Vector3<f32>,
+}
+
+impl InstanceData {
+    pub fn new(model: Matrix4<f32>, colour: Vector3<f32>) -> Self {
+        Self { model, colour }
+    }
 }
 
 pub trait ObjectBuilder: Object {
@@ -86,16 +170,23 @@ pub trait ObjectBuilder: Object {
         );
 
         let vertex_buffers = [self.object_buffers().vertex.buffer];
+        let instance_buffers = [self.object_buffers().instance.buffer];
+        let instance_count = self.object_instances().len().max(1) as u32;
 
         devices
             .logical
             .device
             .cmd_bind_vertex_buffers(command_buffer, 0, &vertex_buffers, offsets);
 
+        devices
+            .logical
+            .device
+            .cmd_bind_vertex_buffers(command_buffer, 1, &instance_buffers, offsets);
+
         devices.logical.device.cmd_draw(
             command_buffer,
             self.object_vertices_and_indices().vertices.len() as u32,
-            1,
+            instance_count,
             0,
             0,
         );
@@ -105,25 +196,97 @@ pub trait ObjectBuilder: Object {
                 command_buffer,
                 self.object_buffers().index.buffer,
                 0,
-                vk::IndexType::UINT16,
+                self.object_vertices_and_indices().indices.index_type(),
             );
 
             devices.logical.device.cmd_draw_indexed(
                 command_buffer,
                 self.object_vertices_and_indices().indices.len() as u32,
-                1,
+                instance_count,
                 0,
                 0,
                 0,
             );
         }
     }
+
+    /// Re-uploads this object's model-matrix uniform for the current frame.
+    /// Called once per tick from the main loop, after any `translate`/
+    /// `rotate`/`scale` calls, so mutating an object's transform and letting
+    /// `update` run is enough to animate it.
+    ///
+    /// # Safety
+    ///
+    /// Expand on safety of this function
+    unsafe fn update(&mut self, dt: f32, devices: &Devices) {
+        let _ = dt;
+
+        let model = self.object_transform().model_matrix();
+        let uniform_memory = self.object_buffers().uniform.memory;
+
+        memory::map_memory(
+            &devices.logical.device,
+            uniform_memory,
+            size_of::<Matrix4<f32>>() as vk::DeviceSize,
+            std::slice::from_ref(&model),
+        );
+    }
 }
 
-#[derive(Clone, new)]
+#[derive(Clone)]
 pub struct VerticesAndIndices {
     vertices: Vec<Vertex>,
-    indices: Vec<u16>,
+    indices: Indices,
+}
+
+impl VerticesAndIndices {
+    pub fn new(vertices: Vec<Vertex>, indices: impl Into<Indices>) -> Self {
+        Self {
+            vertices,
+            indices: indices.into(),
+        }
+    }
+}
+
+/// A mesh's index buffer contents, sized to the narrowest type that fits:
+/// `u16` for the procedural shapes and small imports, `u32` once a mesh
+/// (e.g. an imported OBJ) exceeds 65,535 unique vertices.
+#[derive(Clone, Debug)]
+pub enum Indices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl Indices {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::U16(indices) => indices.len(),
+            Self::U32(indices) => indices.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn index_type(&self) -> vk::IndexType {
+        match self {
+            Self::U16(_) => vk::IndexType::UINT16,
+            Self::U32(_) => vk::IndexType::UINT32,
+        }
+    }
+}
+
+impl From<Vec<u16>> for Indices {
+    fn from(indices: Vec<u16>) -> Self {
+        Self::U16(indices)
+    }
+}
+
+impl From<Vec<u32>> for Indices {
+    fn from(indices: Vec<u32>) -> Self {
+        Self::U32(indices)
+    }
 }
 
 #[derive(Clone, Copy, Debug, new)]
@@ -144,6 +307,8 @@ pub struct Buffer {
 pub struct ModelBuffers {
     pub vertex: Buffer,
     pub index: Buffer,
+    pub instance: Buffer,
+    pub uniform: Buffer,
 }
 
 // #[derive(Clone)]
@@ -15,6 +15,29 @@ pub(crate) struct SwapChainSupport {
     present_modes: Vec<PresentModeKHR>,
 }
 
+/// Caller preferences for swapchain selection: an ordered list of
+/// `(format, colour space)` pairs and an ordered list of present modes.
+/// Selection walks each list and picks the first entry the surface actually
+/// supports, falling back to the previous hardcoded defaults (an sRGB 8-bit
+/// format and `MAILBOX`/`FIFO`) if nothing in the list matches.
+#[derive(Clone, Debug)]
+pub struct SwapChainConfig {
+    pub preferred_formats: Vec<(vk::Format, vk::ColorSpaceKHR)>,
+    pub preferred_present_modes: Vec<vk::PresentModeKHR>,
+}
+
+impl Default for SwapChainConfig {
+    fn default() -> Self {
+        Self {
+            preferred_formats: vec![(
+                vk::Format::R8G8B8A8_SRGB,
+                vk::ColorSpaceKHR::EXTENDED_SRGB_NONLINEAR_EXT,
+            )],
+            preferred_present_modes: vec![vk::PresentModeKHR::MAILBOX, vk::PresentModeKHR::FIFO],
+        }
+    }
+}
+
 pub struct SwapChain {
     pub loader: Swapchain,
     pub swap_chain: vk::SwapchainKHR,
@@ -22,14 +45,73 @@ pub struct SwapChain {
     pub extent: vk::Extent2D,
     pub images: Vec<vk::Image>,
     pub image_views: Vec<vk::ImageView>,
+    pub config: SwapChainConfig,
 }
 
 impl SwapChain {
     pub fn new(
+        instance_devices: &InstanceDevices,
+        surface: vk::SurfaceKHR,
+        surface_loader: &Surface,
+        window: &Window,
+        config: SwapChainConfig,
+    ) -> SwapChain {
+        Self::build(
+            instance_devices,
+            surface,
+            surface_loader,
+            window,
+            vk::SwapchainKHR::null(),
+            config,
+        )
+    }
+
+    /// Rebuilds the swapchain in place for a resized window, or after
+    /// `acquire_next_image`/`queue_present` report
+    /// `ERROR_OUT_OF_DATE_KHR`/`SUBOPTIMAL_KHR`. The old swapchain is passed
+    /// as `old_swapchain` so the driver can hand resources straight over,
+    /// then destroyed once the new one exists. The graphics pipeline and
+    /// framebuffers depend on `extent`, so callers must rebuild those (e.g.
+    /// viewport/scissor, framebuffers) after calling this.
+    pub fn recreate(
+        &mut self,
+        instance_devices: &InstanceDevices,
+        surface: vk::SurfaceKHR,
+        surface_loader: &Surface,
+        window: &Window,
+    ) {
+        let old_swap_chain = self.swap_chain;
+
+        let rebuilt = Self::build(
+            instance_devices,
+            surface,
+            surface_loader,
+            window,
+            old_swap_chain,
+            self.config.clone(),
+        );
+
+        unsafe {
+            for &image_view in &self.image_views {
+                instance_devices
+                    .devices
+                    .logical
+                    .device
+                    .destroy_image_view(image_view, None);
+            }
+            self.loader.destroy_swapchain(old_swap_chain, None);
+        }
+
+        *self = rebuilt;
+    }
+
+    fn build(
         InstanceDevices { instance, devices }: &InstanceDevices,
         surface: vk::SurfaceKHR,
         surface_loader: &Surface,
         window: &Window,
+        old_swapchain: vk::SwapchainKHR,
+        config: SwapChainConfig,
     ) -> SwapChain {
         let SwapChainSupport {
             capabilities,
@@ -37,9 +119,9 @@ impl SwapChain {
             present_modes,
         } = query_swap_chain_support(devices, surface, surface_loader);
 
-        let surface_format = choose_swap_surface_format(&surface_formats);
+        let surface_format = choose_swap_surface_format(&surface_formats, &config);
 
-        let present_mode = choose_present_mode(present_modes);
+        let present_mode = choose_present_mode(present_modes, &config);
 
         let extent = choose_swap_extent(capabilities, window);
 
@@ -62,7 +144,7 @@ impl SwapChain {
             .composite_alpha(vk::CompositeAlphaFlagsKHR::OPAQUE)
             .present_mode(present_mode)
             .clipped(true)
-            .old_swapchain(vk::SwapchainKHR::null());
+            .old_swapchain(old_swapchain);
 
         let queue_family_indices =
             device::find_queue_family(instance, devices.physical.device, surface_loader, &surface);
@@ -101,6 +183,7 @@ impl SwapChain {
                 image_format: surface_format.format,
                 extent,
                 image_views,
+                config,
             }
         }
     }
@@ -177,24 +260,44 @@ pub(crate) fn query_swap_chain_support(
     SwapChainSupport::new(capabilities, formats, present_modes)
 }
 
-fn choose_swap_surface_format(formats: &[vk::SurfaceFormatKHR]) -> vk::SurfaceFormatKHR {
-    for format in formats {
-        if format.format == vk::Format::R8G8B8A8_SRGB
-            && format.color_space == vk::ColorSpaceKHR::EXTENDED_SRGB_NONLINEAR_EXT
+fn choose_swap_surface_format(
+    formats: &[vk::SurfaceFormatKHR],
+    config: &SwapChainConfig,
+) -> vk::SurfaceFormatKHR {
+    let defaults = SwapChainConfig::default();
+
+    for &(format, color_space) in config
+        .preferred_formats
+        .iter()
+        .chain(&defaults.preferred_formats)
+    {
+        if let Some(&surface_format) = formats
+            .iter()
+            .find(|f| f.format == format && f.color_space == color_space)
         {
-            return *format;
+            return surface_format;
         }
     }
 
     formats[0]
 }
 
-fn choose_present_mode(present_modes: Vec<vk::PresentModeKHR>) -> vk::PresentModeKHR {
-    for present_mode in present_modes {
-        if present_mode == vk::PresentModeKHR::MAILBOX {
-            return present_mode;
+fn choose_present_mode(
+    present_modes: Vec<vk::PresentModeKHR>,
+    config: &SwapChainConfig,
+) -> vk::PresentModeKHR {
+    let defaults = SwapChainConfig::default();
+
+    for &preferred in config
+        .preferred_present_modes
+        .iter()
+        .chain(&defaults.preferred_present_modes)
+    {
+        if present_modes.contains(&preferred) {
+            return preferred;
         }
     }
+
     vk::PresentModeKHR::FIFO
 }
 
@@ -0,0 +1,132 @@
+use crate::device::Devices;
+use ash::{vk, Device};
+
+pub const MAX_FRAMES_IN_FLIGHT: usize = 2;
+
+/// A ring of per-frame synchronization primitives, plus one fence per
+/// swapchain image, so the renderer can have `MAX_FRAMES_IN_FLIGHT` frames
+/// in flight on the GPU without either corrupting an image still being
+/// presented or racing the next acquire against it.
+pub struct FrameSync {
+    pub image_available: Vec<vk::Semaphore>,
+    pub render_finished: Vec<vk::Semaphore>,
+    pub in_flight_fences: Vec<vk::Fence>,
+    pub images_in_flight: Vec<vk::Fence>,
+    pub current_frame: usize,
+}
+
+impl FrameSync {
+    pub fn new(devices: &Devices, image_count: usize) -> Self {
+        let semaphore_info = vk::SemaphoreCreateInfo::builder();
+        let fence_info = vk::FenceCreateInfo::builder().flags(vk::FenceCreateFlags::SIGNALED);
+
+        let mut image_available = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT);
+        let mut render_finished = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT);
+        let mut in_flight_fences = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT);
+
+        for _ in 0..MAX_FRAMES_IN_FLIGHT {
+            unsafe {
+                image_available.push(
+                    devices
+                        .logical
+                        .device
+                        .create_semaphore(&semaphore_info, None)
+                        .expect("Failed to create image available semaphore!"),
+                );
+                render_finished.push(
+                    devices
+                        .logical
+                        .device
+                        .create_semaphore(&semaphore_info, None)
+                        .expect("Failed to create render finished semaphore!"),
+                );
+                in_flight_fences.push(
+                    devices
+                        .logical
+                        .device
+                        .create_fence(&fence_info, None)
+                        .expect("Failed to create in-flight fence!"),
+                );
+            }
+        }
+
+        Self {
+            image_available,
+            render_finished,
+            in_flight_fences,
+            images_in_flight: vec![vk::Fence::null(); image_count],
+            current_frame: 0,
+        }
+    }
+
+    pub fn image_available_semaphore(&self) -> vk::Semaphore {
+        self.image_available[self.current_frame]
+    }
+
+    pub fn render_finished_semaphore(&self) -> vk::Semaphore {
+        self.render_finished[self.current_frame]
+    }
+
+    pub fn in_flight_fence(&self) -> vk::Fence {
+        self.in_flight_fences[self.current_frame]
+    }
+
+    /// Waits on the current frame's in-flight fence before reusing its
+    /// command buffer/uniforms.
+    ///
+    /// # Safety
+    ///
+    /// Expand on the safety of this function
+    pub unsafe fn wait_for_frame(&self, device: &Device) {
+        device
+            .wait_for_fences(
+                std::slice::from_ref(&self.in_flight_fences[self.current_frame]),
+                true,
+                u64::MAX,
+            )
+            .expect("Failed to wait for in-flight fence!");
+    }
+
+    /// Called with the `image_index` returned by `acquire_next_image`: if
+    /// that swapchain image is still owned by an earlier frame, waits on its
+    /// fence first, then hands it to the current frame.
+    ///
+    /// # Safety
+    ///
+    /// Expand on the safety of this function
+    pub unsafe fn sync_image(&mut self, device: &Device, image_index: usize) {
+        let image_in_flight = self.images_in_flight[image_index];
+
+        if image_in_flight != vk::Fence::null() {
+            device
+                .wait_for_fences(std::slice::from_ref(&image_in_flight), true, u64::MAX)
+                .expect("Failed to wait for image in-flight fence!");
+        }
+
+        self.images_in_flight[image_index] = self.in_flight_fences[self.current_frame];
+    }
+
+    /// # Safety
+    ///
+    /// Expand on the safety of this function
+    pub unsafe fn reset_in_flight_fence(&self, device: &Device) {
+        device
+            .reset_fences(std::slice::from_ref(&self.in_flight_fences[self.current_frame]))
+            .expect("Failed to reset in-flight fence!");
+    }
+
+    pub fn advance_frame(&mut self) {
+        self.current_frame = (self.current_frame + 1) % MAX_FRAMES_IN_FLIGHT;
+    }
+
+    /// # Safety
+    ///
+    /// Expand on the safety of this function
+    pub unsafe fn destroy(&self, device: &Device) {
+        for i in 0..MAX_FRAMES_IN_FLIGHT {
+            device.destroy_semaphore(self.image_available[i], None);
+            device.destroy_semaphore(self.render_finished[i], None);
+            device.destroy_fence(self.in_flight_fences[i], None);
+        }
+    }
+}